@@ -0,0 +1,692 @@
+use core::fmt::Display;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::crew::*;
+use crate::discount::*;
+use crate::headshot::HeadshotPackage;
+use crate::money::Money;
+use crate::payment::*;
+use crate::quote::*;
+use crate::rates::*;
+use crate::roster::RosterEntry;
+use crate::tax::*;
+use crate::travel::{Leg, Travel};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "JobFile")]
+pub struct Job {
+    pub shoots: Vec<ShootType>,
+    pub expenses: u32,
+    pub drone: bool,
+    pub travel: Travel,
+    pub client_rate: ClientRate,
+    pub promo_code: String,
+    pub discounts: Vec<Discount>,
+    //name of a jurisdiction in the rates file, no tax when empty
+    pub jurisdiction: String,
+    pub payment_plan: PaymentPlan,
+}
+
+impl Default for Job {
+    fn default() -> Job {
+        Job {
+            shoots: vec![ShootType::default()],
+            expenses: 0,
+            drone: false,
+            travel: Travel::default(),
+            client_rate: ClientRate::Standard,
+            promo_code: String::new(),
+            discounts: Vec::new(),
+            jurisdiction: String::new(),
+            payment_plan: PaymentPlan::Standard,
+        }
+    }
+}
+
+//a job as it was saved to the quote library or the app state by any version,
+//quotes from before several shoots per job have a single `shoot_type` and quotes from
+//before travel legs have `travel_hours`, `travel_people` and `travel_days`
+#[derive(Deserialize)]
+#[serde(default)]
+struct JobFile {
+    shoots: Vec<ShootType>,
+    expenses: u32,
+    drone: bool,
+    travel: Travel,
+    client_rate: ClientRate,
+    promo_code: String,
+    discounts: Vec<Discount>,
+    jurisdiction: String,
+    payment_plan: PaymentPlan,
+    shoot_type: Option<ShootType>,
+    travel_hours: Option<u32>,
+    travel_people: Option<u32>,
+    travel_days: Option<u32>,
+}
+
+impl Default for JobFile {
+    fn default() -> JobFile {
+        let Job { shoots, expenses, drone, travel, client_rate, promo_code, discounts, jurisdiction, payment_plan } =
+            Job::default();
+        JobFile {
+            shoots,
+            expenses,
+            drone,
+            travel,
+            client_rate,
+            promo_code,
+            discounts,
+            jurisdiction,
+            payment_plan,
+            shoot_type: None,
+            travel_hours: None,
+            travel_people: None,
+            travel_days: None,
+        }
+    }
+}
+
+impl From<JobFile> for Job {
+    fn from(file: JobFile) -> Job {
+        Job {
+            shoots: match file.shoot_type {
+                Some(shoot) => vec![shoot],
+                None => file.shoots,
+            },
+            expenses: file.expenses,
+            drone: file.drone,
+            travel: match file.travel_hours {
+                //one leg, and per diem only came with trips over two hours
+                Some(hours) => Travel {
+                    legs: if hours > 0 { vec![Leg { hours: hours as f32, ..Leg::default() }] } else { Vec::new() },
+                    people: file.travel_people.unwrap_or(1),
+                    nights: 0,
+                    meal_days: if hours > 2 { file.travel_days.unwrap_or(2) } else { 0 },
+                },
+                None => file.travel,
+            },
+            client_rate: file.client_rate,
+            promo_code: file.promo_code,
+            discounts: file.discounts,
+            jurisdiction: file.jurisdiction,
+            payment_plan: file.payment_plan,
+        }
+    }
+}
+
+impl Job {
+    //a new quote with one hourly shoot for the first active photographer
+    pub fn new(rates: &RateCard) -> Job {
+        Job { shoots: vec![ShootKind::Hourly.default_shoot(rates)], ..Job::default() }
+    }
+
+    pub fn calc_price(&self, rates: &RateCard) -> Quote {
+        let mut quote = Quote::default();
+        for (index, shoot) in self.shoots.iter().enumerate() {
+            for mut item in shoot.calc_price(rates).items {
+                if self.shoots.len() > 1 {
+                    item.label = format!("{}. {}", index + 1, item.label);
+                }
+                quote.items.push(item);
+            }
+        }
+
+        //shared by every shoot, charged once per quote
+        quote.add(ItemKind::Drone, "Drone", if self.drone { 1.0 } else { 0.0 }, rates.drone);
+        quote.add(ItemKind::Expenses, "Expenses", self.expenses as f32, rates.expenses);
+        quote.items.extend(self.travel.calc_price(rates).items);
+
+        let discounts = self.all_discounts(rates);
+        apply_discounts(&mut quote, &discounts);
+        if let Some(jurisdiction) = rates.tax.jurisdiction(&self.jurisdiction) {
+            apply_tax(&mut quote, &discounts, jurisdiction);
+        }
+        quote
+    }
+
+    //days on site for package discounts: hourly and conference shoots are a day each,
+    //half days pair up into days and headshot sessions count their own days
+    pub fn shoot_days(&self) -> u32 {
+        self.shoots
+            .iter()
+            .map(|shoot| match shoot {
+                ShootType::Hourly { hours, .. } | ShootType::Conference { hours, .. } => (*hours > 0.0) as u32,
+                ShootType::HalfDayBased { halves, .. } => halves.div_ceil(2),
+                ShootType::Headshot { days, .. } => *days,
+            })
+            .sum()
+    }
+
+    pub fn milestones(&self, rates: &RateCard) -> Vec<Milestone> {
+        match &self.payment_plan {
+            PaymentPlan::Standard => rates.payments.standard(requires_deposit(self, rates)),
+            PaymentPlan::Custom(milestones) => milestones.clone(),
+        }
+    }
+
+    //when each part of the quoted total is due
+    pub fn schedule(&self, rates: &RateCard, quote: &Quote) -> Result<Vec<Payment>, PaymentError> {
+        payment_schedule(&self.milestones(rates), quote.total())
+    }
+
+    //package, client rate, promo code and then the discounts entered by hand.
+    //unknown promo codes are ignored here, the calculator flags them while typing.
+    pub fn all_discounts(&self, rates: &RateCard) -> Vec<Discount> {
+        let discounts = &rates.discounts;
+        discounts
+            .multi_day(self.shoot_days())
+            .into_iter()
+            .chain(discounts.client(self.client_rate))
+            .chain(discounts.promo(&self.promo_code).map(|promo| promo.discount.clone()))
+            .chain(self.discounts.iter().cloned())
+            .collect()
+    }
+}
+
+fn calc_hourly(
+    rates: &RateCard,
+    hours: f32,
+    image_prep: bool,
+    assistant_hours: f32,
+    photographer: &Photographer,
+    use_higher_prep_price: bool,
+    use_higher_assistant_price: bool,
+) -> Quote {
+    let mut quote = Quote::default();
+    quote.add(ItemKind::Photography, format!("{} hourly", photographer.roster_name(rates)), hours, photographer.get_hourly(rates));
+    add_image_prep(rates, &mut quote, image_prep, use_higher_prep_price);
+    add_assistant(rates, &mut quote, assistant_hours, use_higher_assistant_price);
+    quote
+}
+
+fn calc_half_day(
+    rates: &RateCard,
+    halves: u32,
+    image_prep: bool,
+    assistant_hours: f32,
+    photographer: &Photographer,
+    use_higher_prep_price: bool,
+    use_higher_assistant_price: bool,
+) -> Quote {
+    let mut quote = Quote::default();
+    for (line, quantity, rate) in photographer.half_days(rates, halves) {
+        quote.add(ItemKind::Photography, format!("{} {}", photographer.roster_name(rates), line), quantity, rate);
+    }
+    add_image_prep(rates, &mut quote, image_prep, use_higher_prep_price);
+    add_assistant(rates, &mut quote, assistant_hours, use_higher_assistant_price);
+    quote
+}
+
+fn calc_headshot(rates: &RateCard, heads: u32, headshot_type: &HeadshotType, editing: bool, retouch_level: &RetouchLevel, extra_retouched_photos: u32, days: u32) -> Quote {
+    let mut quote = Quote::default();
+    //nothing is charged for a package that is not in the rates file, the calculator flags it
+    let Some(package) = headshot_type.package(rates) else {
+        return quote;
+    };
+    match &package.session {
+        Some(session) => {
+            quote.add(ItemKind::Photography, format!("{} headshot session", package.name), 1.0, session.fee);
+            quote.add(ItemKind::Photography, "Extra session days", days.saturating_sub(1) as f32, session.extra_day);
+            quote.add(
+                ItemKind::Photography,
+                format!("People over {}", session.included_heads),
+                heads.saturating_sub(session.included_heads) as f32,
+                session.per_extra_head,
+            );
+            //the included retouching is in the fee, a better level is charged the difference
+            let upgrade = retouch_level.get_price_per(rates) - package.retouching.get_price_per(rates);
+            quote.add(
+                ItemKind::Retouching,
+                format!("Retouching upgrade ({})", retouch_level),
+                heads as f32,
+                upgrade.max(Money::ZERO),
+            );
+        }
+        None => {
+            let hours = package.hours(heads);
+            quote.add(ItemKind::Photography, format!("{} headshot hours", package.name), hours, package.hourly);
+            quote.add(ItemKind::Assistant, "Assistant hours", hours * package.assistants, rates.assistant);
+            quote.add(
+                ItemKind::Retouching,
+                format!("Retouching ({})", retouch_level),
+                heads as f32,
+                retouch_level.get_price_per(rates),
+            );
+        }
+    }
+    quote.add(ItemKind::Retouching, "Extra retouched photos", extra_retouched_photos as f32, rates.extra_retouch);
+    let editing = editing && package.on_site_editing;
+    quote.add(ItemKind::Editing, "On site editing", if editing { days as f32 } else { 0.0 }, rates.on_site_editing);
+    quote
+}
+
+fn calc_conference(rates: &RateCard, hours: f32, extra_cost: Money) -> Quote {
+    let mut quote = Quote::default();
+    quote.add(ItemKind::Photography, "Conference hours", hours, rates.conference_hourly);
+    quote.add(ItemKind::Retouching, "Retouching / extra costs", 1.0, extra_cost);
+    quote
+}
+
+fn add_image_prep(rates: &RateCard, quote: &mut Quote, image_prep: bool, use_higher_prep_price: bool) {
+    if image_prep {
+        quote.add(
+            ItemKind::ImagePrep,
+            "Image prep",
+            1.0,
+            if use_higher_prep_price { rates.higher_image_prep } else { rates.image_prep },
+        );
+    }
+}
+
+fn add_assistant(rates: &RateCard, quote: &mut Quote, assistant_hours: f32, use_higher_assistant_price: bool) {
+    quote.add(
+        ItemKind::Assistant,
+        "Assistant hours",
+        assistant_hours,
+        if use_higher_assistant_price { rates.higher_assistant } else { rates.assistant },
+    );
+}
+
+fn crew_text(crew: &[CrewMember]) -> String {
+    match crew.len() {
+        0 => String::new(),
+        count => format!(" and {} more crew", count),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ShootType {
+    Hourly {
+        hours: f32,
+        image_prep: bool,
+        assistant_hours: f32,
+        use_higher_prep_price: bool,
+        use_higher_assistant_price: bool,
+        photographer: Photographer,
+        //everyone else on the shoot, old quotes have nobody
+        #[serde(default)]
+        crew: Vec<CrewMember>,
+    },
+    HalfDayBased {
+        halves: u32,
+        image_prep: bool,
+        assistant_hours: f32,
+        use_higher_prep_price: bool,
+        use_higher_assistant_price: bool,
+        photographer: Photographer,
+        //everyone else on the shoot, old quotes have nobody
+        #[serde(default)]
+        crew: Vec<CrewMember>,
+    },
+    Headshot {
+        heads: u32,
+        headshot_type: HeadshotType,
+        retouch_level: RetouchLevel,
+        editing: bool,
+        extra_retouched_photos: u32,
+        days: u32,
+    },
+    Conference{
+        hours: f32,
+        extra_cost: Money,
+    }
+}
+
+//an hourly shoot with nobody booked yet, the calculator starts on `ShootKind::default_shoot`
+impl Default for ShootType {
+    fn default() -> ShootType {
+        ShootKind::Hourly.shoot_with(Photographer::default())
+    }
+}
+
+impl ShootType {
+    pub fn kind(&self) -> ShootKind {
+        match self {
+            ShootType::Hourly { .. } => ShootKind::Hourly,
+            ShootType::HalfDayBased { .. } => ShootKind::HalfDayBased,
+            ShootType::Headshot { .. } => ShootKind::Headshot,
+            ShootType::Conference { .. } => ShootKind::Conference,
+        }
+    }
+
+    //everyone booked from the roster, headshot and conference shoots name nobody
+    pub fn photographers(&self) -> Vec<&Photographer> {
+        match self {
+            ShootType::Hourly { photographer, crew, .. } | ShootType::HalfDayBased { photographer, crew, .. } => {
+                let crew = crew.iter().filter_map(|member| match &member.rate {
+                    CrewRate::Roster(photographer) => Some(photographer),
+                    _ => None,
+                });
+                std::iter::once(photographer).chain(crew).collect()
+            }
+            ShootType::Headshot { .. } | ShootType::Conference { .. } => Vec::new(),
+        }
+    }
+
+    //picks up where the other kind was left in `remembered`, carrying over the inputs
+    //both kinds share, and remembers the current inputs for switching back
+    pub fn switch_kind(&mut self, kind: ShootKind, remembered: &mut HashMap<ShootKind, ShootType>, rates: &RateCard) {
+        if self.kind() == kind {
+            return;
+        }
+        let mut next = remembered.remove(&kind).unwrap_or_else(|| kind.default_shoot(rates));
+        next.carry_shared(self);
+        let previous = std::mem::replace(self, next);
+        remembered.insert(previous.kind(), previous);
+    }
+
+    //hourly and half day shoots book the same photographer, crew and extras
+    fn carry_shared(&mut self, from: &ShootType) {
+        let (prep, assistant, higher_prep, higher_assistant, from_photographer, from_crew) = match from {
+            ShootType::Hourly {
+                image_prep,
+                assistant_hours,
+                use_higher_prep_price,
+                use_higher_assistant_price,
+                photographer,
+                crew,
+                ..
+            }
+            | ShootType::HalfDayBased {
+                image_prep,
+                assistant_hours,
+                use_higher_prep_price,
+                use_higher_assistant_price,
+                photographer,
+                crew,
+                ..
+            } => (*image_prep, *assistant_hours, *use_higher_prep_price, *use_higher_assistant_price, photographer, crew),
+            _ => return,
+        };
+        match self {
+            ShootType::Hourly {
+                image_prep,
+                assistant_hours,
+                use_higher_prep_price,
+                use_higher_assistant_price,
+                photographer,
+                crew,
+                ..
+            }
+            | ShootType::HalfDayBased {
+                image_prep,
+                assistant_hours,
+                use_higher_prep_price,
+                use_higher_assistant_price,
+                photographer,
+                crew,
+                ..
+            } => {
+                *image_prep = prep;
+                *assistant_hours = assistant;
+                *use_higher_prep_price = higher_prep;
+                *use_higher_assistant_price = higher_assistant;
+                *photographer = from_photographer.clone();
+                *crew = from_crew.clone();
+            }
+            _ => {}
+        }
+    }
+
+    //one line summary of what was booked, for estimates
+    pub fn description(&self) -> String {
+        match self {
+            ShootType::Hourly { hours, photographer, crew, .. } => {
+                format!("Hourly shoot with {}{}, {} hours", photographer, crew_text(crew), hours)
+            }
+            ShootType::HalfDayBased { halves, photographer, crew, .. } => {
+                format!("Half day shoot with {}{}, {} half days", photographer, crew_text(crew), halves)
+            }
+            ShootType::Headshot { heads, headshot_type, days, .. } => {
+                format!("{} headshot session, {} people over {} days", headshot_type, heads, days)
+            }
+            ShootType::Conference { hours, .. } => format!("Conference coverage, {} hours", hours),
+        }
+    }
+
+    pub fn features(&self, rates: &RateCard) -> Option<String> {
+        match self {
+            ShootType::Headshot { heads, headshot_type, .. } => {
+                headshot_type.package(rates).map(|package| package.features_text(*heads))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn calc_price(&self, rates: &RateCard) -> Quote {
+        match self {
+            ShootType::Hourly {
+                hours,
+                image_prep,
+                assistant_hours,
+                photographer,
+                use_higher_prep_price,
+                use_higher_assistant_price,
+                crew,
+            } => {
+                let mut quote = calc_hourly(rates, *hours, *image_prep, *assistant_hours, photographer, *use_higher_prep_price, *use_higher_assistant_price);
+                quote.items.extend(calc_crew(rates, crew, false).items);
+                quote
+            }
+            ShootType::HalfDayBased {
+                halves,
+                image_prep,
+                assistant_hours,
+                photographer,
+                use_higher_prep_price,
+                use_higher_assistant_price,
+                crew,
+            } => {
+                let mut quote = calc_half_day(rates, *halves, *image_prep, *assistant_hours, photographer, *use_higher_prep_price, *use_higher_assistant_price);
+                quote.items.extend(calc_crew(rates, crew, true).items);
+                quote
+            }
+            ShootType::Headshot {
+                heads,
+                headshot_type,
+                editing,
+                retouch_level,
+                extra_retouched_photos,
+                days,
+            } => calc_headshot(rates, *heads, headshot_type, *editing, retouch_level, *extra_retouched_photos, *days),
+            ShootType::Conference { hours, extra_cost } => calc_conference(rates, *hours, *extra_cost),
+        }
+    }
+}
+
+//the name of a headshot package in the rates file
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HeadshotType(pub String);
+
+impl Default for HeadshotType {
+    fn default() -> HeadshotType {
+        HeadshotType::new("Large")
+    }
+}
+
+impl HeadshotType {
+    pub fn new(name: impl Into<String>) -> HeadshotType {
+        HeadshotType(name.into())
+    }
+
+    //None once the package has been taken out of the rates file
+    pub fn package<'a>(&self, rates: &'a RateCard) -> Option<&'a HeadshotPackage> {
+        rates.headshot_package(&self.0)
+    }
+}
+
+impl Display for HeadshotType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for ShootType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+//which variant a shoot is, without its inputs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShootKind {
+    Hourly,
+    HalfDayBased,
+    Headshot,
+    Conference,
+}
+
+impl ShootKind {
+    pub const ALL: [ShootKind; 4] = [
+        ShootKind::Hourly,
+        ShootKind::HalfDayBased,
+        ShootKind::Headshot,
+        ShootKind::Conference,
+    ];
+
+    //what a shoot starts as when this kind is picked, booking the first active photographer
+    pub fn default_shoot(self, rates: &RateCard) -> ShootType {
+        self.shoot_with(Photographer::first(rates))
+    }
+
+    fn shoot_with(self, photographer: Photographer) -> ShootType {
+        match self {
+            ShootKind::Hourly => ShootType::Hourly {
+                hours: 0.0,
+                image_prep: false,
+                assistant_hours: 0.0,
+                use_higher_prep_price: false,
+                use_higher_assistant_price: false,
+                photographer: photographer.clone(),
+                crew: Vec::new(),
+            },
+            ShootKind::HalfDayBased => ShootType::HalfDayBased {
+                halves: 0,
+                image_prep: false,
+                assistant_hours: 0.0,
+                use_higher_prep_price: false,
+                use_higher_assistant_price: false,
+                photographer,
+                crew: Vec::new(),
+            },
+            ShootKind::Headshot => ShootType::Headshot {
+                heads: 0,
+                headshot_type: HeadshotType::default(),
+                retouch_level: RetouchLevel::Discount,
+                editing: false,
+                extra_retouched_photos: 0,
+                days: 1,
+            },
+            ShootKind::Conference => ShootType::Conference { hours: 0.0, extra_cost: Money::ZERO },
+        }
+    }
+}
+
+impl Display for ShootKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShootKind::Hourly => write!(f, "Hourly"),
+            ShootKind::HalfDayBased => write!(f, "Half Day"),
+            ShootKind::Headshot => write!(f, "Headshot"),
+            ShootKind::Conference => write!(f, "Conference"),
+        }
+    }
+}
+
+//a name on the roster in the rates file, empty when nobody has been picked
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Photographer(pub String);
+
+impl Photographer {
+    pub fn new(name: impl Into<String>) -> Photographer {
+        Photographer(name.into())
+    }
+
+    //who new shoots, crew members and comparisons start on
+    pub fn first(rates: &RateCard) -> Photographer {
+        rates.photographers.active().next().map_or_else(Photographer::default, |entry| Photographer::new(entry.name.as_str()))
+    }
+
+    //None once they have been taken off the roster
+    pub fn rates<'a>(&self, rates: &'a RateCard) -> Option<&'a RosterEntry> {
+        rates.photographers.get(&self.0)
+    }
+    //spelled the way the roster has it, for line items
+    pub fn roster_name<'a>(&'a self, rates: &'a RateCard) -> &'a str {
+        self.rates(rates).map_or(self.0.as_str(), |entry| entry.name.as_str())
+    }
+    //nobody is charged for a photographer who is not on the roster, the calculator flags them
+    pub(crate) fn get_hourly(&self, rates: &RateCard) -> Money {
+        self.rates(rates).map_or(Money::ZERO, |entry| entry.hourly)
+    }
+    fn get_first_half_day(&self, rates: &RateCard) -> Money {
+        self.rates(rates).map_or(Money::ZERO, |entry| entry.first_half_day)
+    }
+    fn get_second_half_day(&self, rates: &RateCard) -> Money {
+        self.rates(rates).map_or(Money::ZERO, |entry| entry.second_half_day)
+    }
+    //every odd half day starts a new day at the first half day rate
+    pub(crate) fn half_days(&self, rates: &RateCard, halves: u32) -> [(&'static str, f32, Money); 2] {
+        [
+            ("first half day", (halves as f32 / 2.0).ceil(), self.get_first_half_day(rates)),
+            ("second half day", (halves as f32 / 2.0).floor(), self.get_second_half_day(rates)),
+        ]
+    }
+}
+
+impl Display for Photographer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RetouchLevel {
+    Student,
+    Discount,
+    Corporate,
+    Full,
+}
+
+impl RetouchLevel {
+    pub const ALL: [RetouchLevel; 4] =
+        [RetouchLevel::Student, RetouchLevel::Discount, RetouchLevel::Corporate, RetouchLevel::Full];
+
+    pub fn get_price_per(&self, rates: &RateCard) -> Money {
+        match self {
+            RetouchLevel::Student => rates.retouch.student,
+            RetouchLevel::Discount => rates.retouch.discount,
+            RetouchLevel::Corporate => rates.retouch.corporate,
+            RetouchLevel::Full => rates.retouch.full,
+        }
+    }
+}
+
+impl FromStr for RetouchLevel {
+    type Err = String;
+    fn from_str(s: &str) -> Result<RetouchLevel, String> {
+        match s.to_lowercase().as_str() {
+            "student" => Ok(RetouchLevel::Student),
+            "discount" => Ok(RetouchLevel::Discount),
+            "corporate" => Ok(RetouchLevel::Corporate),
+            "full" => Ok(RetouchLevel::Full),
+            _ => Err(format!("unknown retouch level `{}` (student, discount, corporate or full)", s)),
+        }
+    }
+}
+
+impl Display for RetouchLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetouchLevel::Student => write!(f, "Student"),
+            RetouchLevel::Discount => write!(f, "Discount"),
+            RetouchLevel::Corporate => write!(f, "Corporate/Under 20 People"),
+            RetouchLevel::Full => write!(f, "Full Price/Special Needs"),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::calc::*;
+use crate::money::Money;
+use crate::quote::Quote;
+use crate::rates::RateCard;
+
+//how many hours of shooting one half day covers
+pub const HALF_DAY_HOURS: f32 = 4.0;
+
+//the inputs every compared shoot type is priced from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompareInputs {
+    pub hours: f32,
+    pub image_prep: bool,
+    pub assistant_hours: f32,
+    pub use_higher_prep_price: bool,
+    pub use_higher_assistant_price: bool,
+    pub photographer: Photographer,
+}
+
+impl Default for CompareInputs {
+    fn default() -> CompareInputs {
+        CompareInputs {
+            hours: 0.0,
+            image_prep: false,
+            assistant_hours: 0.0,
+            use_higher_prep_price: false,
+            use_higher_assistant_price: false,
+            photographer: Photographer::default(),
+        }
+    }
+}
+
+impl CompareInputs {
+    pub fn new(rates: &RateCard) -> CompareInputs {
+        CompareInputs { photographer: Photographer::first(rates), ..CompareInputs::default() }
+    }
+
+    //enough half days to cover the hours
+    pub fn halves(&self) -> u32 {
+        (self.hours / HALF_DAY_HOURS).ceil() as u32
+    }
+
+    fn hourly(&self) -> ShootType {
+        ShootType::Hourly {
+            hours: self.hours,
+            image_prep: self.image_prep,
+            assistant_hours: self.assistant_hours,
+            use_higher_prep_price: self.use_higher_prep_price,
+            use_higher_assistant_price: self.use_higher_assistant_price,
+            photographer: self.photographer.clone(),
+            crew: Vec::new(),
+        }
+    }
+
+    fn half_day(&self) -> ShootType {
+        ShootType::HalfDayBased {
+            halves: self.halves(),
+            image_prep: self.image_prep,
+            assistant_hours: self.assistant_hours,
+            use_higher_prep_price: self.use_higher_prep_price,
+            use_higher_assistant_price: self.use_higher_assistant_price,
+            photographer: self.photographer.clone(),
+            crew: Vec::new(),
+        }
+    }
+
+    fn conference(&self) -> ShootType {
+        ShootType::Conference { hours: self.hours, extra_cost: Money::ZERO }
+    }
+}
+
+//where hourly stops being cheaper than the half days covering the entered hours
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BreakEven {
+    //hourly costs the same as the half days at these hours
+    At(f32),
+    //hourly is cheaper for every hour the half days cover, up to these hours
+    HourlyCheaper(f32),
+}
+
+pub struct Comparison {
+    pub options: Vec<(ShootKind, Quote)>,
+    //None when the photographer has no hourly rate
+    pub break_even: Option<BreakEven>,
+}
+
+impl Comparison {
+    //the first of the cheapest options when several cost the same
+    pub fn cheapest(&self) -> Option<ShootKind> {
+        self.options
+            .iter()
+            .min_by_key(|(_, quote)| quote.total())
+            .map(|(kind, _)| *kind)
+    }
+}
+
+//prices each shoot type with the job's shared extras (drone, expenses, travel), ignoring its shoots
+pub fn compare(job: &Job, inputs: &CompareInputs, rates: &RateCard) -> Comparison {
+    let options = [
+        (ShootKind::Hourly, inputs.hourly()),
+        (ShootKind::HalfDayBased, inputs.half_day()),
+        (ShootKind::Conference, inputs.conference()),
+    ]
+    .into_iter()
+    .map(|(kind, shoot)| {
+        let job = Job { shoots: vec![shoot], ..job.clone() };
+        (kind, job.calc_price(rates))
+    })
+    .collect();
+
+    //prep and assistant cost the same either way, so only the photographer decides
+    let photographer_only = CompareInputs { photographer: inputs.photographer.clone(), ..CompareInputs::default() };
+    let per_hour = CompareInputs { hours: 1.0, ..photographer_only.clone() }.hourly().calc_price(rates).total();
+    let half_days = CompareInputs { hours: inputs.hours, ..photographer_only }.half_day().calc_price(rates).total();
+    let covered = inputs.halves() as f32 * HALF_DAY_HOURS;
+    let break_even = (per_hour > Money::ZERO).then(|| {
+        let hours = (half_days.as_dollars_f64() / per_hour.as_dollars_f64()) as f32;
+        if hours <= covered {
+            BreakEven::At(hours)
+        } else {
+            BreakEven::HourlyCheaper(covered)
+        }
+    });
+
+    Comparison { options, break_even }
+}
@@ -0,0 +1,134 @@
+//What a job costs the business, as opposed to what the client is charged:
+//  - photographers are paid their roster pay rate, by the hour or by the half day, headshot
+//    photographers the package's `pay_hourly` for every hour on site and conference
+//    photographers `costs.conference_hourly`
+//  - assistants are paid `costs.assistant` an hour, or `costs.higher_assistant` when the client
+//    is charged the higher rate
+//  - every headshot and extra retouched photo goes to the retoucher at `costs.retouch_per_image`
+//  - the drone operator is paid `costs.drone` per job
+//  - travel expenses (flights, mileage, tolls, parking, meals, lodging) and conference extra
+//    costs are paid out as charged, travel time is not
+
+use serde::{Deserialize, Serialize};
+
+use crate::calc::*;
+use crate::crew::*;
+use crate::money::Money;
+use crate::quote::*;
+use crate::rates::RateCard;
+
+//the `[costs]` section of the rates file
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CostRates {
+    pub assistant: Money,
+    pub higher_assistant: Money,
+    pub retouch_per_image: Money,
+    pub drone: Money,
+    pub conference_hourly: Money,
+}
+
+fn photographer_pay(quote: &mut Quote, rates: &RateCard, photographer: &Photographer, hours: f32, halves: u32) {
+    let Some(entry) = photographer.rates(rates) else {
+        return;
+    };
+    quote.add(ItemKind::Photography, format!("{} hourly pay", entry.name), hours, entry.pay_hourly);
+    quote.add(ItemKind::Photography, format!("{} half day pay", entry.name), halves as f32, entry.pay_half_day);
+}
+
+fn assistant_pay(quote: &mut Quote, rates: &RateCard, hours: f32, higher: bool) {
+    let (label, rate) = if higher {
+        ("Higher rate assistant pay", rates.costs.higher_assistant)
+    } else {
+        ("Assistant pay", rates.costs.assistant)
+    };
+    quote.add(ItemKind::Assistant, label, hours, rate);
+}
+
+fn crew_pay(quote: &mut Quote, rates: &RateCard, crew: &[CrewMember], half_day_shoot: bool) {
+    for member in crew {
+        match &member.rate {
+            CrewRate::Roster(photographer) if member.by_half_day(half_day_shoot) => {
+                photographer_pay(quote, rates, photographer, 0.0, member.halves)
+            }
+            CrewRate::Roster(photographer) => photographer_pay(quote, rates, photographer, member.hours, 0),
+            CrewRate::Assistant => assistant_pay(quote, rates, member.hours, false),
+            CrewRate::HigherAssistant => assistant_pay(quote, rates, member.hours, true),
+        }
+    }
+}
+
+pub fn shoot_cost(shoot: &ShootType, rates: &RateCard) -> Quote {
+    let mut quote = Quote::default();
+    match shoot {
+        ShootType::Hourly { hours, assistant_hours, use_higher_assistant_price, photographer, crew, .. } => {
+            photographer_pay(&mut quote, rates, photographer, *hours, 0);
+            assistant_pay(&mut quote, rates, *assistant_hours, *use_higher_assistant_price);
+            crew_pay(&mut quote, rates, crew, false);
+        }
+        ShootType::HalfDayBased { halves, assistant_hours, use_higher_assistant_price, photographer, crew, .. } => {
+            photographer_pay(&mut quote, rates, photographer, 0.0, *halves);
+            assistant_pay(&mut quote, rates, *assistant_hours, *use_higher_assistant_price);
+            crew_pay(&mut quote, rates, crew, true);
+        }
+        ShootType::Headshot { heads, headshot_type, extra_retouched_photos, .. } => {
+            let Some(package) = headshot_type.package(rates) else {
+                return quote;
+            };
+            let hours = package.hours(*heads);
+            quote.add(ItemKind::Photography, "Photographer pay", hours, package.pay_hourly);
+            assistant_pay(&mut quote, rates, hours * package.assistants, false);
+            quote.add(
+                ItemKind::Retouching,
+                "Retoucher",
+                (heads + extra_retouched_photos) as f32,
+                rates.costs.retouch_per_image,
+            );
+        }
+        ShootType::Conference { hours, extra_cost } => {
+            quote.add(ItemKind::Photography, "Photographer pay", *hours, rates.costs.conference_hourly);
+            quote.add(ItemKind::Retouching, "Retouching / extra costs", 1.0, *extra_cost);
+        }
+    }
+    quote
+}
+
+//every payout for the job, laid out like the client's breakdown
+pub fn job_cost(job: &Job, rates: &RateCard) -> Quote {
+    let mut quote = Quote::default();
+    for (index, shoot) in job.shoots.iter().enumerate() {
+        for mut item in shoot_cost(shoot, rates).items {
+            if job.shoots.len() > 1 {
+                item.label = format!("{}. {}", index + 1, item.label);
+            }
+            quote.items.push(item);
+        }
+    }
+    quote.add(ItemKind::Drone, "Drone operator", if job.drone { 1.0 } else { 0.0 }, rates.costs.drone);
+    let travel = &job.travel;
+    let time = travel.time(rates).total();
+    quote.add(ItemKind::Travel, "Travel expenses", 1.0, travel.calc_price(rates).total() - time);
+    quote
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrossMargin {
+    //what the client pays before tax, which is passed on
+    pub price: Money,
+    pub cost: Money,
+}
+
+impl GrossMargin {
+    pub fn new(job: &Job, rates: &RateCard, quote: &Quote) -> GrossMargin {
+        GrossMargin { price: quote.total_before_tax(), cost: job_cost(job, rates).total() }
+    }
+
+    pub fn amount(&self) -> Money {
+        self.price - self.cost
+    }
+
+    //share of the price that is kept, None for a free job
+    pub fn percent(&self) -> Option<f32> {
+        (self.price > Money::ZERO).then(|| (self.amount().as_dollars_f64() / self.price.as_dollars_f64() * 100.0) as f32)
+    }
+}
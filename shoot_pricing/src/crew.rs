@@ -0,0 +1,114 @@
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::calc::Photographer;
+use crate::quote::*;
+use crate::rates::RateCard;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrewRole {
+    #[default]
+    Photographer,
+    Assistant,
+}
+
+impl CrewRole {
+    pub const ALL: [CrewRole; 2] = [CrewRole::Photographer, CrewRole::Assistant];
+
+    pub fn item_kind(self) -> ItemKind {
+        match self {
+            CrewRole::Photographer => ItemKind::Photography,
+            CrewRole::Assistant => ItemKind::Assistant,
+        }
+    }
+}
+
+impl Display for CrewRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrewRole::Photographer => write!(f, "photographer"),
+            CrewRole::Assistant => write!(f, "assistant"),
+        }
+    }
+}
+
+//where a crew member's rate comes from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CrewRate {
+    Roster(Photographer),
+    Assistant,
+    HigherAssistant,
+}
+
+impl Default for CrewRate {
+    fn default() -> CrewRate {
+        CrewRate::Roster(Photographer::default())
+    }
+}
+
+impl Display for CrewRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrewRate::Roster(photographer) => write!(f, "{}", photographer),
+            CrewRate::Assistant => write!(f, "Assistant"),
+            CrewRate::HigherAssistant => write!(f, "Higher rate assistant"),
+        }
+    }
+}
+
+//someone booked on an hourly or half day shoot besides its own photographer and assistant
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrewMember {
+    pub role: CrewRole,
+    pub rate: CrewRate,
+    //assistant rates are always by the hour, roster rates by the hour on hourly shoots
+    pub hours: f32,
+    //roster rates on half day shoots
+    pub halves: u32,
+}
+
+impl Default for CrewMember {
+    fn default() -> CrewMember {
+        CrewMember { role: CrewRole::Photographer, rate: CrewRate::default(), hours: 0.0, halves: 0 }
+    }
+}
+
+impl CrewMember {
+    //whether they are booked by the half day on a half day shoot, by the hour otherwise
+    pub fn by_half_day(&self, half_day_shoot: bool) -> bool {
+        half_day_shoot && matches!(self.rate, CrewRate::Roster(_))
+    }
+
+    //one or two lines per person, each labelled with who it is
+    pub fn calc_price(&self, rates: &RateCard, half_day_shoot: bool) -> Quote {
+        let kind = self.role.item_kind();
+        //only worth saying when it is not obvious from the rate
+        let role = match (&self.rate, self.role) {
+            (CrewRate::Roster(_), CrewRole::Assistant) => " as assistant",
+            (CrewRate::Assistant | CrewRate::HigherAssistant, CrewRole::Photographer) => " as photographer",
+            _ => "",
+        };
+        let mut quote = Quote::default();
+        match &self.rate {
+            CrewRate::Roster(photographer) if half_day_shoot => {
+                for (line, halves, rate) in photographer.half_days(rates, self.halves) {
+                    quote.add(kind, format!("{} {}{}", photographer.roster_name(rates), line, role), halves, rate);
+                }
+            }
+            CrewRate::Roster(photographer) => {
+                quote.add(kind, format!("{} hourly{}", photographer.roster_name(rates), role), self.hours, photographer.get_hourly(rates));
+            }
+            CrewRate::Assistant => quote.add(kind, format!("Assistant hours{}", role), self.hours, rates.assistant),
+            CrewRate::HigherAssistant => {
+                quote.add(kind, format!("Higher rate assistant hours{}", role), self.hours, rates.higher_assistant)
+            }
+        }
+        quote
+    }
+}
+
+pub fn calc_crew(rates: &RateCard, crew: &[CrewMember], half_day_shoot: bool) -> Quote {
+    Quote { items: crew.iter().flat_map(|member| member.calc_price(rates, half_day_shoot).items).collect() }
+}
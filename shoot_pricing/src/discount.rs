@@ -0,0 +1,216 @@
+use core::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::Money;
+use crate::quote::*;
+use crate::rates::{check_names, RateCardError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DiscountAmount {
+    Percent(f32),
+    Fixed(Money),
+}
+
+impl Display for DiscountAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscountAmount::Percent(percent) => write!(f, "{}%", percent),
+            DiscountAmount::Fixed(amount) => write!(f, "{}", amount),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Discount {
+    pub name: String,
+    pub amount: DiscountAmount,
+    //the kinds of item it comes off, the whole quote when empty
+    #[serde(default)]
+    pub items: Vec<ItemKind>,
+}
+
+impl Discount {
+    pub fn percent(name: impl Into<String>, percent: f32) -> Discount {
+        Discount { name: name.into(), amount: DiscountAmount::Percent(percent), items: Vec::new() }
+    }
+
+    //out of range amounts are clamped, a fixed amount never takes more than the items it applies to
+    pub fn amount_off(&self, quote: &Quote) -> Money {
+        let base = quote.total_of(&self.items);
+        match self.amount {
+            DiscountAmount::Percent(percent) => base.times(percent.clamp(0.0, 100.0) / 100.0),
+            DiscountAmount::Fixed(amount) => amount.min(base).max(Money::ZERO),
+        }
+    }
+}
+
+//how much each discount takes off each kind of charge. percentages are worked out on the
+//undiscounted items, but no discount takes more than is left of its items after the ones
+//before it, and that is spread over them in proportion to what is left of each
+pub fn discount_shares(quote: &Quote, discounts: &[Discount]) -> Vec<Vec<(ItemKind, Money)>> {
+    let mut left: Vec<(ItemKind, Money)> =
+        ItemKind::CHARGES.iter().map(|kind| (*kind, quote.total_of(&[*kind]).max(Money::ZERO))).collect();
+    discounts
+        .iter()
+        .map(|discount| {
+            let applies = |kind: &ItemKind| discount.items.is_empty() || discount.items.contains(kind);
+            let base: Money = left.iter().filter(|(kind, _)| applies(kind)).map(|(_, money)| *money).sum();
+            let amount = discount.amount_off(quote).min(base);
+            let spread: Vec<usize> =
+                (0..left.len()).filter(|&i| applies(&left[i].0) && left[i].1 > Money::ZERO).collect();
+            let mut unspread = amount;
+            let mut shares = Vec::new();
+            for &i in &spread {
+                let share = amount.times((left[i].1.as_dollars_f64() / base.as_dollars_f64()) as f32).min(left[i].1).min(unspread);
+                unspread -= share;
+                shares.push((left[i].0, share));
+            }
+            //cents lost to rounding come off the first items with anything left
+            for (&i, (_, share)) in spread.iter().zip(&mut shares) {
+                let cents = unspread.min(left[i].1 - *share);
+                *share += cents;
+                unspread -= cents;
+            }
+            for (&i, (_, share)) in spread.iter().zip(&shares) {
+                left[i].1 -= *share;
+            }
+            shares
+        })
+        .collect()
+}
+
+pub fn discount_amounts(quote: &Quote, discounts: &[Discount]) -> Vec<Money> {
+    discount_shares(quote, discounts).into_iter().map(|shares| shares.into_iter().map(|(_, share)| share).sum()).collect()
+}
+
+//adds each discount as a negative line item
+pub fn apply_discounts(quote: &mut Quote, discounts: &[Discount]) {
+    for (discount, amount) in discounts.iter().zip(discount_amounts(quote, discounts)) {
+        quote.add(ItemKind::Discount, &discount.name, 1.0, -amount);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClientRate {
+    #[default]
+    Standard,
+    Nonprofit,
+    Student,
+}
+
+impl ClientRate {
+    pub const ALL: [ClientRate; 3] = [ClientRate::Standard, ClientRate::Nonprofit, ClientRate::Student];
+}
+
+impl FromStr for ClientRate {
+    type Err = String;
+    fn from_str(s: &str) -> Result<ClientRate, String> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(ClientRate::Standard),
+            "nonprofit" => Ok(ClientRate::Nonprofit),
+            "student" => Ok(ClientRate::Student),
+            _ => Err(format!("unknown client rate `{}` (standard, nonprofit or student)", s)),
+        }
+    }
+}
+
+impl Display for ClientRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientRate::Standard => write!(f, "Standard"),
+            ClientRate::Nonprofit => write!(f, "Nonprofit"),
+            ClientRate::Student => write!(f, "Student"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MultiDayDiscount {
+    pub min_days: u32,
+    pub percent: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PromoCode {
+    pub code: String,
+    pub discount: Discount,
+}
+
+//the `[discounts]` section of the rates file, percentages are 0 to 100
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiscountRates {
+    pub nonprofit: f32,
+    pub student: f32,
+    pub multi_day: Vec<MultiDayDiscount>,
+    pub promo_codes: Vec<PromoCode>,
+}
+
+impl Default for DiscountRates {
+    fn default() -> DiscountRates {
+        DiscountRates {
+            nonprofit: 10.0,
+            student: 15.0,
+            multi_day: Vec::new(),
+            promo_codes: Vec::new(),
+        }
+    }
+}
+
+impl DiscountRates {
+    pub fn client(&self, rate: ClientRate) -> Option<Discount> {
+        let percent = match rate {
+            ClientRate::Standard => return None,
+            ClientRate::Nonprofit => self.nonprofit,
+            ClientRate::Student => self.student,
+        };
+        Some(Discount::percent(format!("{} rate ({}%)", rate, percent), percent))
+    }
+
+    //the biggest package the days qualify for
+    pub fn multi_day(&self, days: u32) -> Option<Discount> {
+        self.multi_day
+            .iter()
+            .filter(|tier| days >= tier.min_days)
+            .max_by_key(|tier| tier.min_days)
+            .map(|tier| Discount::percent(format!("{} day package ({}%)", tier.min_days, tier.percent), tier.percent))
+    }
+
+    //codes are matched ignoring case and surrounding spaces
+    pub fn promo(&self, code: &str) -> Option<&PromoCode> {
+        let code = code.trim();
+        if code.is_empty() {
+            return None;
+        }
+        self.promo_codes.iter().find(|promo| promo.code.trim().eq_ignore_ascii_case(code))
+    }
+
+    pub fn validate(&self) -> Result<(), RateCardError> {
+        check_names("discounts.promo_codes", self.promo_codes.iter().map(|promo| promo.code.as_str()))?;
+        let mut percents = vec![
+            ("discounts.nonprofit".to_string(), self.nonprofit),
+            ("discounts.student".to_string(), self.student),
+        ];
+        for tier in &self.multi_day {
+            percents.push((format!("discounts.multi_day.{}", tier.min_days), tier.percent));
+        }
+        for promo in &self.promo_codes {
+            let name = format!("discounts.promo_codes.{}", promo.code);
+            match promo.discount.amount {
+                DiscountAmount::Percent(percent) => percents.push((name, percent)),
+                DiscountAmount::Fixed(amount) if amount < Money::ZERO => {
+                    return Err(RateCardError::Negative(name, amount))
+                }
+                DiscountAmount::Fixed(_) => {}
+            }
+        }
+        match percents.into_iter().find(|(_, percent)| !(0.0..=100.0).contains(percent)) {
+            Some((name, percent)) => Err(RateCardError::Percent(name, percent)),
+            None => Ok(()),
+        }
+    }
+}
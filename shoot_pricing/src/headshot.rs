@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+use crate::calc::RetouchLevel;
+use crate::money::Money;
+
+//a kind of headshot session from the `[[headshot_packages]]` of the rates file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HeadshotPackage {
+    pub name: String,
+    pub hourly: Money,
+    //priced per session instead of by the hour
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<SessionPricing>,
+    pub heads_per_hour: u32,
+    //on site for set-up and teardown on top of the photo hours
+    pub setup_hours: f32,
+    //assistants on site for every hour
+    pub assistants: f32,
+    //what the photographer is paid for every hour on site
+    #[serde(default)]
+    pub pay_hourly: Money,
+    //what a new session of this package is quoted with, session packages include it in the fee
+    pub retouching: RetouchLevel,
+    #[serde(default)]
+    pub requires_deposit: bool,
+    //whether on site editing can be booked with it
+    #[serde(default = "offered")]
+    pub on_site_editing: bool,
+    //the blurb lines, the throughput and hours are added from the numbers above
+    pub features: String,
+}
+
+fn offered() -> bool {
+    true
+}
+
+impl HeadshotPackage {
+    pub fn new(name: impl Into<String>) -> HeadshotPackage {
+        HeadshotPackage {
+            name: name.into(),
+            hourly: Money::ZERO,
+            session: None,
+            heads_per_hour: 12,
+            setup_hours: 1.0,
+            assistants: 0.0,
+            pay_hourly: Money::ZERO,
+            retouching: RetouchLevel::Discount,
+            requires_deposit: false,
+            on_site_editing: true,
+            features: String::new(),
+        }
+    }
+
+    //a new hour for every started batch of `heads_per_hour` people
+    pub fn photo_hours(&self, heads: u32) -> f32 {
+        heads.div_ceil(self.heads_per_hour.max(1)) as f32
+    }
+
+    pub fn hours(&self, heads: u32) -> f32 {
+        self.photo_hours(heads) + self.setup_hours
+    }
+
+    //marketing blurb copied into emails and estimates
+    pub fn features_text(&self, heads: u32) -> String {
+        let mut text = "Features:".to_string();
+        for line in self.features.lines().filter(|line| !line.trim().is_empty()) {
+            text.push('\n');
+            text.push_str(line.trim());
+        }
+        if let Some(session) = &self.session {
+            text.push_str(&format!(
+                "\nfor up to {} {}, {} for each additional person",
+                session.included_heads,
+                if session.included_heads == 1 { "person" } else { "people" },
+                session.per_extra_head,
+            ));
+        } else {
+            let photo_hours = self.photo_hours(heads);
+            text.push_str(&format!(
+                "\n{}/people per hour\nfor up to {} photo {} (plus {} extra {} on site for set-up & teardown)",
+                self.heads_per_hour,
+                photo_hours,
+                if photo_hours == 1.0 { "hour" } else { "hours" },
+                self.setup_hours,
+                if self.setup_hours == 1.0 { "hour" } else { "hours" },
+            ));
+        }
+        text
+    }
+}
+
+//a session fee for the first day that covers the first `included_heads` people with the
+//package's retouching, every day after that is `extra_day` and everyone after those people
+//is charged `per_extra_head`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SessionPricing {
+    pub fee: Money,
+    pub included_heads: u32,
+    pub per_extra_head: Money,
+    pub extra_day: Money,
+}
+
+pub fn default_packages() -> Vec<HeadshotPackage> {
+    vec![
+        HeadshotPackage {
+            hourly: Money::dollars(275),
+            assistants: 2.0,
+            requires_deposit: true,
+            features: "nice retouching included\non-site photo choice on iPad".to_string(),
+            ..HeadshotPackage::new("Large")
+        },
+        HeadshotPackage {
+            hourly: Money::dollars(200),
+            assistants: 1.0,
+            retouching: RetouchLevel::Corporate,
+            features: "business-level retouching included (blemishes, flyaway hair)\n\
+                       on-site photo choice on iPad\n\
+                       Online sign up & direct email delivery to subjects"
+                .to_string(),
+            ..HeadshotPackage::new("Team")
+        },
+        HeadshotPackage {
+            session: Some(SessionPricing {
+                fee: Money::dollars(400),
+                included_heads: 5,
+                per_extra_head: Money::dollars(50),
+                extra_day: Money::dollars(400),
+            }),
+            on_site_editing: false,
+            features: "simple lighting as needed\n\
+                       minor retouching included (blemishes)\n\
+                       fully trained HuthPhoto Team photographer"
+                .to_string(),
+            ..HeadshotPackage::new("Small")
+        },
+    ]
+}
@@ -0,0 +1,14 @@
+pub mod calc;
+pub mod compare;
+pub mod cost;
+pub mod crew;
+pub mod discount;
+pub mod headshot;
+pub mod money;
+pub mod payment;
+pub mod quote;
+pub mod rates;
+pub mod roster;
+pub mod solve;
+pub mod tax;
+pub mod travel;
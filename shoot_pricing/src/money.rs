@@ -0,0 +1,155 @@
+use core::fmt::Display;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+//whole cents, so totals pasted into invoices are exact. arithmetic stops at the largest amount
+//instead of wrapping or panicking, `out_of_range` tells when a price got there
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+    pub const MAX: Money = Money(i64::MAX);
+    pub const MIN: Money = Money(-i64::MAX);
+
+    pub const fn dollars(dollars: i64) -> Money {
+        Money(dollars.saturating_mul(100)).clamped()
+    }
+
+    //rounds half away from zero to the nearest cent
+    pub fn from_dollars_f64(dollars: f64) -> Money {
+        Money((dollars * 100.0).round() as i64).clamped()
+    }
+
+    pub fn as_dollars_f64(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    //price of a (possibly fractional) quantity, rounded to the nearest cent
+    pub fn times(self, quantity: f32) -> Money {
+        Money((self.0 as f64 * quantity as f64).round() as i64).clamped()
+    }
+
+    //at or past the largest amount, so whatever it came from was too large to price
+    pub fn out_of_range(self) -> bool {
+        self == Money::MAX || self == Money::MIN
+    }
+
+    //keeps MIN the negative of MAX so negating never overflows
+    const fn clamped(self) -> Money {
+        if self.0 < Money::MIN.0 {
+            Money::MIN
+        } else {
+            self
+        }
+    }
+
+    //plain "1234.50" for pasting into other tools
+    pub fn to_plain_string(self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        format!("{}{}.{:02}", sign, self.0.abs() / 100, self.0.abs() % 100)
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-${}", (-*self).to_plain_string())
+        } else {
+            write!(f, "${}", self.to_plain_string())
+        }
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0.saturating_add(rhs.0)).clamped()
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Money) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+    fn sub(self, rhs: Money) -> Money {
+        Money(self.0.saturating_sub(rhs.0)).clamped()
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Money) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+//written as plain dollars (`drone = 150.0`) so files stay hand editable
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_dollars_f64())
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        f64::deserialize(deserializer).map(Money::from_dollars_f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_to_the_nearest_cent() {
+        assert_eq!(Money::from_dollars_f64(4123.4999).to_plain_string(), "4123.50");
+        assert_eq!(Money::from_dollars_f64(0.004).to_plain_string(), "0.00");
+        assert_eq!(Money::from_dollars_f64(0.005).to_plain_string(), "0.01");
+        assert_eq!(Money::dollars(40).times(0.1), Money::dollars(4));
+        assert_eq!(Money::dollars(275).times(1.5), Money::from_dollars_f64(412.5));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Money::dollars(1500).to_string(), "$1500.00");
+        assert_eq!(Money::from_dollars_f64(-5.5).to_string(), "-$5.50");
+        assert_eq!(Money::from_dollars_f64(-0.05).to_plain_string(), "-0.05");
+    }
+
+    #[test]
+    fn huge_amounts_stop_at_the_largest_instead_of_overflowing() {
+        assert_eq!(Money::dollars(150).times(1e30), Money::MAX);
+        assert_eq!(Money::dollars(150).times(f32::INFINITY), Money::MAX);
+        assert_eq!(Money::MAX + Money::dollars(1), Money::MAX);
+        assert_eq!(Money::MIN - Money::dollars(1), Money::MIN);
+        assert_eq!(-Money::MIN, Money::MAX);
+        assert_eq!(Money::from_dollars_f64(-1e300), Money::MIN);
+        assert!(std::iter::repeat_n(Money::MAX, 3).sum::<Money>().out_of_range());
+        assert!(!Money::dollars(1_000_000).out_of_range());
+    }
+
+    #[test]
+    fn many_small_amounts_stay_exact() {
+        let total: Money = std::iter::repeat_n(Money::from_dollars_f64(0.1), 1000).sum();
+        assert_eq!(total, Money::dollars(100));
+    }
+}
@@ -0,0 +1,139 @@
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::calc::*;
+use crate::money::Money;
+use crate::rates::{RateCard, RateCardError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Due {
+    Booking,
+    //days after the shoot, negative for before it
+    ShootDay(i32),
+}
+
+impl Display for Due {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Due::Booking => write!(f, "on booking"),
+            Due::ShootDay(0) => write!(f, "on the shoot day"),
+            Due::ShootDay(1) => write!(f, "the day after the shoot"),
+            Due::ShootDay(-1) => write!(f, "the day before the shoot"),
+            Due::ShootDay(days) if *days > 0 => write!(f, "{} days after the shoot", days),
+            Due::ShootDay(days) => write!(f, "{} days before the shoot", -days),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Milestone {
+    pub name: String,
+    pub percent: f32,
+    pub due: Due,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum PaymentPlan {
+    //deposit and balance for the jobs that need one, in full on delivery otherwise
+    #[default]
+    Standard,
+    Custom(Vec<Milestone>),
+}
+
+//the `[payments]` section of the rates file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaymentRates {
+    pub deposit_percent: f32,
+    //images are delivered, and the balance is due, this many days after the shoot
+    pub delivery_days: i32,
+}
+
+impl Default for PaymentRates {
+    fn default() -> PaymentRates {
+        PaymentRates { deposit_percent: 50.0, delivery_days: 14 }
+    }
+}
+
+impl PaymentRates {
+    pub fn standard(&self, deposit: bool) -> Vec<Milestone> {
+        let delivery = Due::ShootDay(self.delivery_days);
+        if deposit {
+            vec![
+                Milestone { name: "Deposit".to_string(), percent: self.deposit_percent, due: Due::Booking },
+                Milestone {
+                    name: "Balance on delivery".to_string(),
+                    percent: 100.0 - self.deposit_percent,
+                    due: delivery,
+                },
+            ]
+        } else {
+            vec![Milestone { name: "Payment on delivery".to_string(), percent: 100.0, due: delivery }]
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), RateCardError> {
+        if (0.0..=100.0).contains(&self.deposit_percent) {
+            Ok(())
+        } else {
+            Err(RateCardError::Percent("payments.deposit_percent".to_string(), self.deposit_percent))
+        }
+    }
+}
+
+//half day bookings and the headshot packages marked for it (large sessions) hold a whole day,
+//so they are only booked against a deposit
+pub fn requires_deposit(job: &Job, rates: &RateCard) -> bool {
+    job.shoots.iter().any(|shoot| match shoot {
+        ShootType::HalfDayBased { halves, .. } => *halves > 0,
+        ShootType::Headshot { headshot_type, heads, .. } => {
+            *heads > 0 && headshot_type.package(rates).is_some_and(|package| package.requires_deposit)
+        }
+        ShootType::Hourly { .. } | ShootType::Conference { .. } => false,
+    })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payment {
+    pub name: String,
+    pub due: Due,
+    pub amount: Money,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PaymentError {
+    //the milestones add up to this many percent instead of 100
+    NotWhole(f32),
+}
+
+impl Display for PaymentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentError::NotWhole(percent) => write!(f, "payments add up to {}% instead of 100%", percent),
+        }
+    }
+}
+
+impl std::error::Error for PaymentError {}
+
+//splits the total over the milestones, the last one takes whatever cents rounding left over
+pub fn payment_schedule(milestones: &[Milestone], total: Money) -> Result<Vec<Payment>, PaymentError> {
+    let percent: f32 = milestones.iter().map(|milestone| milestone.percent).sum();
+    if (percent - 100.0).abs() > 0.001 {
+        return Err(PaymentError::NotWhole(percent));
+    }
+    let mut remaining = total;
+    let mut payments: Vec<Payment> = milestones
+        .iter()
+        .map(|milestone| {
+            let amount = total.times(milestone.percent / 100.0);
+            remaining -= amount;
+            Payment { name: milestone.name.clone(), due: milestone.due, amount }
+        })
+        .collect();
+    if let Some(last) = payments.last_mut() {
+        last.amount += remaining;
+    }
+    Ok(payments)
+}
@@ -0,0 +1,122 @@
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::Money;
+
+//what a line item charges for, so discounts and tax can pick items out of a quote
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemKind {
+    Photography,
+    Assistant,
+    ImagePrep,
+    Retouching,
+    Editing,
+    Drone,
+    Expenses,
+    Travel,
+    PerDiem,
+    Lodging,
+    Discount,
+    Tax,
+}
+
+impl ItemKind {
+    //everything a quote charges for, leaving out discounts and tax
+    pub const CHARGES: [ItemKind; 10] = [
+        ItemKind::Photography,
+        ItemKind::Assistant,
+        ItemKind::ImagePrep,
+        ItemKind::Retouching,
+        ItemKind::Editing,
+        ItemKind::Drone,
+        ItemKind::Expenses,
+        ItemKind::Travel,
+        ItemKind::PerDiem,
+        ItemKind::Lodging,
+    ];
+}
+
+impl Display for ItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemKind::Photography => write!(f, "Photography"),
+            ItemKind::Assistant => write!(f, "Assistant"),
+            ItemKind::ImagePrep => write!(f, "Image prep"),
+            ItemKind::Retouching => write!(f, "Retouching"),
+            ItemKind::Editing => write!(f, "Editing"),
+            ItemKind::Drone => write!(f, "Drone"),
+            ItemKind::Expenses => write!(f, "Expenses"),
+            ItemKind::Travel => write!(f, "Travel"),
+            ItemKind::PerDiem => write!(f, "Per diem"),
+            ItemKind::Lodging => write!(f, "Lodging"),
+            ItemKind::Discount => write!(f, "Discount"),
+            ItemKind::Tax => write!(f, "Tax"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LineItem {
+    pub kind: ItemKind,
+    pub label: String,
+    pub quantity: f32,
+    pub unit_price: Money,
+    pub subtotal: Money,
+}
+
+impl LineItem {
+    pub fn new(kind: ItemKind, label: impl Into<String>, quantity: f32, unit_price: Money) -> LineItem {
+        LineItem {
+            kind,
+            label: label.into(),
+            quantity,
+            unit_price,
+            subtotal: unit_price.times(quantity),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Quote {
+    pub items: Vec<LineItem>,
+}
+
+impl Quote {
+    //zero items (no drone, no assistant...) are left off the breakdown
+    pub fn add(&mut self, kind: ItemKind, label: impl Into<String>, quantity: f32, unit_price: Money) {
+        let item = LineItem::new(kind, label, quantity, unit_price);
+        if item.subtotal != Money::ZERO {
+            self.items.push(item);
+        }
+    }
+
+    pub fn total(&self) -> Money {
+        self.items.iter().map(|item| item.subtotal).sum()
+    }
+
+    pub fn tax(&self) -> Money {
+        self.items.iter().filter(|item| item.kind == ItemKind::Tax).map(|item| item.subtotal).sum()
+    }
+
+    pub fn total_before_tax(&self) -> Money {
+        self.total() - self.tax()
+    }
+
+    //a line or total ran past the largest amount Money holds, so the quote cannot be trusted
+    pub fn out_of_range(&self) -> bool {
+        self.items.iter().any(|item| item.subtotal.out_of_range())
+            || self.total_of(&[]).out_of_range()
+            || self.total().out_of_range()
+    }
+
+    //total of the charges of the given kinds before discounts, every charge when `kinds` is empty
+    pub fn total_of(&self, kinds: &[ItemKind]) -> Money {
+        self.items
+            .iter()
+            .filter(|item| ItemKind::CHARGES.contains(&item.kind))
+            .filter(|item| kinds.is_empty() || kinds.contains(&item.kind))
+            .map(|item| item.subtotal)
+            .sum()
+    }
+}
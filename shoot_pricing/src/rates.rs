@@ -0,0 +1,439 @@
+use core::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cost::CostRates;
+use crate::discount::DiscountRates;
+use crate::headshot::*;
+use crate::money::Money;
+use crate::payment::PaymentRates;
+use crate::roster::*;
+use crate::tax::TaxRates;
+use crate::travel::TravelRates;
+
+const RATES_FILE: &str = "rates.toml";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateCard {
+    pub image_prep: Money,
+    pub higher_image_prep: Money,
+    pub drone: Money,
+    pub assistant: Money,
+    pub higher_assistant: Money,
+    pub expenses: Money,
+    pub on_site_editing: Money,
+    pub extra_retouch: Money,
+    pub conference_hourly: Money,
+    pub travel_short_hourly: Money,
+    pub travel_long_hourly: Money,
+    pub travel_per_diem: Money,
+    pub photographers: Roster,
+    #[serde(default = "default_packages")]
+    pub headshot_packages: Vec<HeadshotPackage>,
+    pub retouch: RetouchRates,
+    //rates files from before discounts existed have no such section
+    #[serde(default)]
+    pub discounts: DiscountRates,
+    #[serde(default)]
+    pub tax: TaxRates,
+    #[serde(default)]
+    pub payments: PaymentRates,
+    //mileage and lodging, the older travel rates above stay where they always were
+    #[serde(default)]
+    pub travel: TravelRates,
+    //what the business pays out, only shown in the internal view
+    #[serde(default)]
+    pub costs: CostRates,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetouchRates {
+    pub student: Money,
+    pub discount: Money,
+    pub corporate: Money,
+    pub full: Money,
+}
+
+impl Default for RateCard {
+    fn default() -> RateCard {
+        RateCard {
+            image_prep: Money::dollars(50),
+            higher_image_prep: Money::dollars(50 * 2),
+            drone: Money::dollars(150),
+            assistant: Money::dollars(40),
+            higher_assistant: Money::dollars(50),
+            expenses: Money::dollars(10),
+            on_site_editing: Money::dollars(100),
+            extra_retouch: Money::dollars(20),
+            conference_hourly: Money::dollars(200),
+            travel_short_hourly: Money::dollars(75),
+            travel_long_hourly: Money::dollars(50),
+            travel_per_diem: Money::dollars(150),
+            headshot_packages: default_packages(),
+            photographers: Roster(vec![
+                RosterEntry::new("Ken", Money::dollars(275), Money::dollars(1500), Money::dollars(1000)),
+                RosterEntry::new("Colin", Money::dollars(225), Money::dollars(1500), Money::dollars(1000)),
+                RosterEntry::new("Team", Money::dollars(150), Money::dollars(600), Money::dollars(600)),
+            ]),
+            retouch: RetouchRates {
+                student: Money::dollars(5),
+                discount: Money::dollars(10),
+                corporate: Money::dollars(20),
+                full: Money::dollars(50),
+            },
+            discounts: DiscountRates::default(),
+            tax: TaxRates::default(),
+            payments: PaymentRates::default(),
+            travel: TravelRates::default(),
+            costs: CostRates::default(),
+        }
+    }
+}
+
+impl RateCard {
+    pub fn from_toml(text: &str) -> Result<RateCard, RateCardError> {
+        let mut table: toml::Table = toml::from_str(text).map_err(RateCardError::Parse)?;
+        migrate_headshot_rates(&mut table)?;
+        let card: RateCard = toml::Value::Table(table).try_into().map_err(RateCardError::Parse)?;
+        card.validate()?;
+        Ok(card)
+    }
+
+    //packages are matched ignoring case and surrounding spaces
+    pub fn headshot_package(&self, name: &str) -> Option<&HeadshotPackage> {
+        let name = name.trim();
+        self.headshot_packages.iter().find(|package| package.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    pub fn load(path: &Path) -> Result<RateCard, RateCardError> {
+        let text = std::fs::read_to_string(path).map_err(RateCardError::Io)?;
+        RateCard::from_toml(&text)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), RateCardError> {
+        self.validate()?;
+        let text = toml::to_string_pretty(self).map_err(RateCardError::Serialize)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(RateCardError::Io)?;
+        }
+        std::fs::write(path, text).map_err(RateCardError::Io)
+    }
+
+    pub fn validate(&self) -> Result<(), RateCardError> {
+        if let Some((name, amount)) = self.named_amounts().into_iter().find(|(_, amount)| !(*amount >= 0.0 && amount.is_finite())) {
+            return Err(RateCardError::NegativeAmount(name, amount));
+        }
+        match self.named_rates().into_iter().find(|(_, rate)| *rate < Money::ZERO) {
+            Some((name, rate)) => Err(RateCardError::Negative(name, rate)),
+            None => {
+                self.photographers.validate()?;
+                check_names("headshot_packages", self.headshot_packages.iter().map(|package| package.name.as_str()))?;
+                if let Some(package) = self.headshot_packages.iter().find(|package| package.heads_per_hour == 0) {
+                    return Err(RateCardError::Zero(format!("headshot_packages.{}.heads_per_hour", package.name)));
+                }
+                self.discounts.validate()?;
+                self.tax.validate()?;
+                self.payments.validate()
+            }
+        }
+    }
+
+    //hours and head counts that are not money, with the key they have in the rates file
+    pub fn named_amounts(&self) -> Vec<(String, f32)> {
+        let mut amounts = vec![("travel.short_trip_hours".to_string(), self.travel.short_trip_hours)];
+        for package in &self.headshot_packages {
+            amounts.push((format!("headshot_packages.{}.setup_hours", package.name), package.setup_hours));
+            amounts.push((format!("headshot_packages.{}.assistants", package.name), package.assistants));
+        }
+        amounts
+    }
+
+    //every rate with the key it has in the rates file
+    pub fn named_rates(&self) -> Vec<(String, Money)> {
+        let mut rates = vec![
+            ("image_prep".to_string(), self.image_prep),
+            ("higher_image_prep".to_string(), self.higher_image_prep),
+            ("drone".to_string(), self.drone),
+            ("assistant".to_string(), self.assistant),
+            ("higher_assistant".to_string(), self.higher_assistant),
+            ("expenses".to_string(), self.expenses),
+            ("on_site_editing".to_string(), self.on_site_editing),
+            ("extra_retouch".to_string(), self.extra_retouch),
+            ("conference_hourly".to_string(), self.conference_hourly),
+            ("travel_short_hourly".to_string(), self.travel_short_hourly),
+            ("travel_long_hourly".to_string(), self.travel_long_hourly),
+            ("travel_per_diem".to_string(), self.travel_per_diem),
+            ("travel.mileage".to_string(), self.travel.mileage),
+            ("travel.lodging".to_string(), self.travel.lodging),
+            ("retouch.student".to_string(), self.retouch.student),
+            ("retouch.discount".to_string(), self.retouch.discount),
+            ("retouch.corporate".to_string(), self.retouch.corporate),
+            ("retouch.full".to_string(), self.retouch.full),
+            ("costs.assistant".to_string(), self.costs.assistant),
+            ("costs.higher_assistant".to_string(), self.costs.higher_assistant),
+            ("costs.retouch_per_image".to_string(), self.costs.retouch_per_image),
+            ("costs.drone".to_string(), self.costs.drone),
+            ("costs.conference_hourly".to_string(), self.costs.conference_hourly),
+        ];
+        for entry in &self.photographers.0 {
+            rates.push((format!("photographers.{}.hourly", entry.name), entry.hourly));
+            rates.push((format!("photographers.{}.first_half_day", entry.name), entry.first_half_day));
+            rates.push((format!("photographers.{}.second_half_day", entry.name), entry.second_half_day));
+            rates.push((format!("photographers.{}.pay_hourly", entry.name), entry.pay_hourly));
+            rates.push((format!("photographers.{}.pay_half_day", entry.name), entry.pay_half_day));
+        }
+        for package in &self.headshot_packages {
+            rates.push((format!("headshot_packages.{}.hourly", package.name), package.hourly));
+            rates.push((format!("headshot_packages.{}.pay_hourly", package.name), package.pay_hourly));
+            if let Some(session) = &package.session {
+                rates.push((format!("headshot_packages.{}.session.fee", package.name), session.fee));
+                rates.push((format!("headshot_packages.{}.session.per_extra_head", package.name), session.per_extra_head));
+                rates.push((format!("headshot_packages.{}.session.extra_day", package.name), session.extra_day));
+            }
+        }
+        rates
+    }
+}
+
+//names are looked up ignoring case, so they have to be unique that way
+pub fn check_names<'a>(list: &str, names: impl Iterator<Item = &'a str>) -> Result<(), RateCardError> {
+    let mut seen: Vec<String> = Vec::new();
+    for name in names {
+        let key = name.trim().to_lowercase();
+        if key.is_empty() {
+            return Err(RateCardError::Unnamed(list.to_string()));
+        }
+        if seen.contains(&key) {
+            return Err(RateCardError::Duplicate(name.to_string(), list.to_string()));
+        }
+        seen.push(key);
+    }
+    Ok(())
+}
+
+//rates files from before headshot packages had one rate per headshot type, they become the
+//rates of the built-in packages
+fn migrate_headshot_rates(table: &mut toml::Table) -> Result<(), RateCardError> {
+    let mut packages = default_packages();
+    let mut migrated = false;
+    for (key, name) in [("large_headshot_hourly", "Large"), ("team_headshot_hourly", "Team"), ("small_headshot", "Small")] {
+        let Some(value) = table.remove(key) else {
+            continue;
+        };
+        let rate: Money = value.try_into().map_err(RateCardError::Parse)?;
+        let package = packages.iter_mut().find(|package| package.name == name).unwrap();
+        match &mut package.session {
+            Some(session) => session.fee = rate,
+            None => package.hourly = rate,
+        }
+        migrated = true;
+    }
+    if migrated && !table.contains_key("headshot_packages") {
+        let packages = toml::Value::try_from(packages).map_err(RateCardError::Serialize)?;
+        table.insert("headshot_packages".to_string(), packages);
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum RateCardError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    Negative(String, Money),
+    //hours or head counts below zero or not a number
+    NegativeAmount(String, f32),
+    Percent(String, f32),
+    Zero(String),
+    //the list with an entry that has no name
+    Unnamed(String),
+    //the name and the list it is in more than once
+    Duplicate(String, String),
+    NoConfigDir,
+}
+
+impl Display for RateCardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateCardError::Io(err) => write!(f, "could not access rates file: {}", err),
+            RateCardError::Parse(err) => write!(f, "invalid rates file: {}", err),
+            RateCardError::Serialize(err) => write!(f, "could not write rates: {}", err),
+            RateCardError::Negative(name, rate) => write!(f, "rate `{}` is negative ({})", name, rate),
+            RateCardError::NegativeAmount(name, amount) => write!(f, "`{}` must be 0 or more ({})", name, amount),
+            RateCardError::Percent(name, percent) => {
+                write!(f, "`{}` must be between 0 and 100% ({}%)", name, percent)
+            }
+            RateCardError::Zero(name) => write!(f, "`{}` must be more than 0", name),
+            RateCardError::Unnamed(list) => write!(f, "an entry in `{}` has no name", list),
+            RateCardError::Duplicate(name, list) => write!(f, "`{}` is in `{}` more than once", name, list),
+            RateCardError::NoConfigDir => write!(f, "no config directory to save rates to"),
+        }
+    }
+}
+
+impl std::error::Error for RateCardError {}
+
+#[derive(Default)]
+pub enum RateSource {
+    #[default]
+    BuiltIn,
+    File(PathBuf),
+    Invalid(PathBuf, RateCardError),
+}
+
+impl Display for RateSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateSource::BuiltIn => write!(f, "built-in rates"),
+            RateSource::File(path) => write!(f, "rates from {}", path.display()),
+            RateSource::Invalid(path, err) => write!(f, "built-in rates, {} was not used: {}", path.display(), err),
+        }
+    }
+}
+
+pub fn rates_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("shoot_calc").join(RATES_FILE))
+}
+
+//falls back to the built-in rates when there is no rates file or it is broken
+pub fn load_rates() -> (RateCard, RateSource) {
+    let Some(path) = rates_path().filter(|path| path.exists()) else {
+        return (RateCard::default(), RateSource::BuiltIn);
+    };
+    match RateCard::load(&path) {
+        Ok(card) => (card, RateSource::File(path)),
+        Err(err) => (RateCard::default(), RateSource::Invalid(path, err)),
+    }
+}
+
+pub fn save_rates(card: &RateCard) -> Result<PathBuf, RateCardError> {
+    let path = rates_path().ok_or(RateCardError::NoConfigDir)?;
+    card.save(&path)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discount::{Discount, PromoCode};
+
+    #[test]
+    fn defaults_round_trip_through_toml() {
+        let text = toml::to_string_pretty(&RateCard::default()).unwrap();
+        assert_eq!(RateCard::from_toml(&text).unwrap(), RateCard::default());
+    }
+
+    #[test]
+    fn missing_rates_are_rejected() {
+        let text = toml::to_string_pretty(&RateCard::default()).unwrap().replace("drone = 150.0\n", "");
+        let err = RateCard::from_toml(&text).unwrap_err();
+        assert!(err.to_string().contains("drone"), "{}", err);
+    }
+
+    #[test]
+    fn negative_rates_are_rejected() {
+        let text = toml::to_string_pretty(&RateCard::default())
+            .unwrap()
+            .replace("student = 5.0", "student = -5.0");
+        let err = RateCard::from_toml(&text).unwrap_err();
+        assert_eq!(err.to_string(), "rate `retouch.student` is negative (-$5.00)");
+    }
+
+    #[test]
+    fn negative_or_missing_package_hours_are_rejected() {
+        let mut card = RateCard::default();
+        card.headshot_packages[1].assistants = -1.0;
+        assert_eq!(card.validate().unwrap_err().to_string(), "`headshot_packages.Team.assistants` must be 0 or more (-1)");
+        card.headshot_packages[1].assistants = 1.0;
+        card.headshot_packages[0].setup_hours = f32::NAN;
+        assert_eq!(card.validate().unwrap_err().to_string(), "`headshot_packages.Large.setup_hours` must be 0 or more (NaN)");
+    }
+
+    #[test]
+    fn negative_short_trips_are_rejected() {
+        let text = toml::to_string_pretty(&RateCard::default())
+            .unwrap()
+            .replace("short_trip_hours = 2.0", "short_trip_hours = -2.0");
+        let err = RateCard::from_toml(&text).unwrap_err();
+        assert_eq!(err.to_string(), "`travel.short_trip_hours` must be 0 or more (-2)");
+    }
+
+    #[test]
+    fn discounts_over_100_percent_are_rejected() {
+        let text = toml::to_string_pretty(&RateCard::default())
+            .unwrap()
+            .replace("nonprofit = 10.0", "nonprofit = 110.0");
+        let err = RateCard::from_toml(&text).unwrap_err();
+        assert_eq!(err.to_string(), "`discounts.nonprofit` must be between 0 and 100% (110%)");
+    }
+
+    #[test]
+    fn duplicate_photographers_are_rejected() {
+        let mut card = RateCard::default();
+        card.photographers.0[1].name = " ken".to_string();
+        assert_eq!(card.validate().unwrap_err().to_string(), "` ken` is in `photographers` more than once");
+    }
+
+    #[test]
+    fn duplicate_or_blank_promo_codes_are_rejected() {
+        let promo = |code: &str| PromoCode { code: code.to_string(), discount: Discount::percent("Promo", 10.0) };
+        let mut card = RateCard::default();
+        card.discounts.promo_codes = vec![promo("SPRING"), promo("spring ")];
+        assert_eq!(card.validate().unwrap_err().to_string(), "`spring ` is in `discounts.promo_codes` more than once");
+        card.discounts.promo_codes = vec![promo(" ")];
+        assert_eq!(card.validate().unwrap_err().to_string(), "an entry in `discounts.promo_codes` has no name");
+    }
+
+    #[test]
+    fn added_jurisdictions_are_named_and_duplicates_rejected() {
+        let mut card = RateCard::default();
+        card.tax.add();
+        card.tax.add();
+        let names: Vec<&str> = card.tax.jurisdictions.iter().map(|jurisdiction| jurisdiction.name.as_str()).collect();
+        assert_eq!(names, ["New jurisdiction", "New jurisdiction 2"]);
+        assert!(card.validate().is_ok());
+        card.tax.jurisdictions[1].name = "new Jurisdiction ".to_string();
+        assert_eq!(card.validate().unwrap_err().to_string(), "`new Jurisdiction ` is in `tax.jurisdictions` more than once");
+        card.tax.jurisdictions[1].name = String::new();
+        assert_eq!(card.validate().unwrap_err().to_string(), "an entry in `tax.jurisdictions` has no name");
+    }
+
+    #[test]
+    fn rates_files_with_a_rate_per_headshot_type_still_load() {
+        let mut text = toml::to_string_pretty(&RateCard::default()).unwrap();
+        let start = text.find("[[headshot_packages]]").unwrap();
+        let end = text.find("[retouch]").unwrap();
+        text.replace_range(start..end, "");
+        let text = format!("large_headshot_hourly = 300.0\nteam_headshot_hourly = 200.0\nsmall_headshot = 450.0\n{}", text);
+        let card = RateCard::from_toml(&text).unwrap();
+        assert_eq!(card.headshot_package("large").unwrap().hourly, Money::dollars(300));
+        assert_eq!(card.headshot_package("small").unwrap().session.as_ref().unwrap().fee, Money::dollars(450));
+        assert_eq!(card.headshot_packages.len(), 3);
+    }
+
+    #[test]
+    fn rates_files_with_named_photographer_tables_still_load() {
+        let text = toml::to_string_pretty(&RateCard::default()).unwrap();
+        let start = text.find("[[photographers]]").unwrap();
+        let end = text.find("[retouch]").unwrap();
+        let old = "[photographers.ken]\nhourly = 275.0\nfirst_half_day = 1500.0\nsecond_half_day = 1000.0\n\n\
+                   [photographers.colin]\nhourly = 225.0\nfirst_half_day = 1500.0\nsecond_half_day = 1000.0\n\n\
+                   [photographers.team]\nhourly = 150.0\nfirst_half_day = 600.0\nsecond_half_day = 600.0\n\n";
+        let text = format!("{}{}{}", &text[..start], old, &text[end..]);
+        let card = RateCard::from_toml(&text).unwrap();
+        let names: Vec<&str> = card.photographers.0.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["Colin", "Ken", "Team"]);
+        assert_eq!(card.photographers.get("ken"), RateCard::default().photographers.get("Ken"));
+    }
+
+    #[test]
+    fn rates_files_without_discounts_still_load() {
+        let mut text = toml::to_string_pretty(&RateCard::default()).unwrap();
+        let start = text.find("[discounts]").unwrap();
+        text.truncate(start);
+        assert_eq!(RateCard::from_toml(&text).unwrap(), RateCard::default());
+    }
+}
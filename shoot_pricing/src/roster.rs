@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::money::Money;
+use crate::rates::{check_names, RateCardError};
+
+//the skill a photographer needs to fly the drone
+pub const DRONE_SKILL: &str = "drone";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RosterEntry {
+    pub name: String,
+    pub hourly: Money,
+    pub first_half_day: Money,
+    pub second_half_day: Money,
+    //what they are paid, nothing for the owner
+    #[serde(default)]
+    pub pay_hourly: Money,
+    #[serde(default)]
+    pub pay_half_day: Money,
+    //free text such as "drone", matched ignoring case
+    #[serde(default)]
+    pub skills: Vec<String>,
+    //retired photographers can no longer be picked but still price the quotes that name them
+    #[serde(default = "active")]
+    pub active: bool,
+}
+
+fn active() -> bool {
+    true
+}
+
+impl RosterEntry {
+    pub fn new(name: impl Into<String>, hourly: Money, first_half_day: Money, second_half_day: Money) -> RosterEntry {
+        RosterEntry {
+            name: name.into(),
+            hourly,
+            first_half_day,
+            second_half_day,
+            pay_hourly: Money::ZERO,
+            pay_half_day: Money::ZERO,
+            skills: Vec::new(),
+            active: true,
+        }
+    }
+
+    pub fn has_skill(&self, skill: &str) -> bool {
+        self.skills.iter().any(|own| own.trim().eq_ignore_ascii_case(skill))
+    }
+}
+
+//the `[[photographers]]` of the rates file, in the order the calculator lists them
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Roster(pub Vec<RosterEntry>);
+
+//rates files from before the roster have a `[photographers.ken]` table per photographer
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NamedRate {
+    hourly: Money,
+    first_half_day: Money,
+    second_half_day: Money,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RosterFile {
+    List(Vec<RosterEntry>),
+    Named(BTreeMap<String, NamedRate>),
+}
+
+impl<'de> Deserialize<'de> for Roster {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Roster, D::Error> {
+        Ok(match RosterFile::deserialize(deserializer)? {
+            RosterFile::List(entries) => Roster(entries),
+            RosterFile::Named(named) => Roster(
+                named
+                    .into_iter()
+                    .map(|(key, rate)| {
+                        let mut chars = key.chars();
+                        let name: String = chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default();
+                        RosterEntry::new(name, rate.hourly, rate.first_half_day, rate.second_half_day)
+                    })
+                    .collect(),
+            ),
+        })
+    }
+}
+
+impl Roster {
+    //names are matched ignoring case and surrounding spaces
+    pub fn get(&self, name: &str) -> Option<&RosterEntry> {
+        let name = name.trim();
+        self.0.iter().find(|entry| entry.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    //who can be picked for a new shoot
+    pub fn active(&self) -> impl Iterator<Item = &RosterEntry> {
+        self.0.iter().filter(|entry| entry.active)
+    }
+
+    //adds a photographer with no rates yet, named so it does not clash with anyone on the roster
+    pub fn add(&mut self) -> &mut RosterEntry {
+        let mut name = "New photographer".to_string();
+        let mut number = 1;
+        while self.get(&name).is_some() {
+            number += 1;
+            name = format!("New photographer {}", number);
+        }
+        self.0.push(RosterEntry::new(name, Money::ZERO, Money::ZERO, Money::ZERO));
+        self.0.last_mut().unwrap()
+    }
+
+    pub fn validate(&self) -> Result<(), RateCardError> {
+        check_names("photographers", self.0.iter().map(|entry| entry.name.as_str()))
+    }
+}
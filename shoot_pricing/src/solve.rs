@@ -0,0 +1,62 @@
+use crate::calc::*;
+use crate::money::Money;
+use crate::quote::Quote;
+use crate::rates::RateCard;
+
+//hours are solved to the quarter hour
+pub const HOUR_STEP: f32 = 0.25;
+//largest number of hours, half days or people the solver will offer
+pub const MAX_QUANTITY: u32 = 1000;
+
+pub struct Solution {
+    pub job: Job,
+    pub quote: Quote,
+}
+
+//sets the one input the solver varies: hours, half days or people
+fn with_steps(shoot: &ShootType, steps: u32) -> ShootType {
+    let mut shoot = shoot.clone();
+    match &mut shoot {
+        ShootType::Hourly { hours, .. } | ShootType::Conference { hours, .. } => *hours = steps as f32 * HOUR_STEP,
+        ShootType::HalfDayBased { halves, .. } => *halves = steps,
+        ShootType::Headshot { heads, .. } => *heads = steps,
+    }
+    shoot
+}
+
+fn max_steps(shoot: &ShootType) -> u32 {
+    match shoot {
+        ShootType::Hourly { .. } | ShootType::Conference { .. } => (MAX_QUANTITY as f32 / HOUR_STEP) as u32,
+        ShootType::HalfDayBased { .. } | ShootType::Headshot { .. } => MAX_QUANTITY,
+    }
+}
+
+//the job with the most hours, half days or people for shoot `index` that still fits the budget,
+//keeping every other input as it is. None when even zero does not fit.
+pub fn solve(job: &Job, index: usize, budget: Money, rates: &RateCard) -> Option<Solution> {
+    let shoot = &job.shoots[index];
+    let price = |steps| {
+        let mut job = job.clone();
+        job.shoots[index] = with_steps(shoot, steps);
+        let quote = job.calc_price(rates);
+        (job, quote)
+    };
+
+    //prices never go down as the quantity goes up, so the largest fit can be searched for.
+    //headshots jump at every new dozen (see HeadshotPackage::hours), which this also handles. a multi-day
+    //package can make a longer booking cheaper, then the answer fits but may not be the largest.
+    let (mut low, mut high) = (0, max_steps(shoot));
+    if price(low).1.total() > budget {
+        return None;
+    }
+    while low < high {
+        let middle = low + (high - low).div_ceil(2);
+        if price(middle).1.total() <= budget {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    let (job, quote) = price(low);
+    Some(Solution { job, quote })
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::discount::*;
+use crate::money::Money;
+use crate::quote::*;
+use crate::rates::{check_names, RateCardError};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Jurisdiction {
+    pub name: String,
+    pub percent: f32,
+    //the kinds of item tax is charged on, e.g. drone and retouching but not per diem
+    pub taxable: Vec<ItemKind>,
+}
+
+impl Jurisdiction {
+    fn taxable_total(&self, quote: &Quote) -> Money {
+        quote.items.iter().filter(|item| self.taxable.contains(&item.kind)).map(|item| item.subtotal).sum()
+    }
+
+    //the taxable items less the part of every discount that came off them
+    pub fn taxable_amount(&self, quote: &Quote, discounts: &[Discount]) -> Money {
+        let discounted: Money = discount_shares(quote, discounts)
+            .into_iter()
+            .flatten()
+            .filter(|(kind, _)| self.taxable.contains(kind))
+            .map(|(_, share)| share)
+            .sum();
+        (self.taxable_total(quote) - discounted).max(Money::ZERO)
+    }
+}
+
+//the `[tax]` section of the rates file, no jurisdictions means no tax is ever charged
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TaxRates {
+    pub jurisdictions: Vec<Jurisdiction>,
+}
+
+impl TaxRates {
+    //names are matched ignoring case and surrounding spaces
+    pub fn jurisdiction(&self, name: &str) -> Option<&Jurisdiction> {
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        self.jurisdictions.iter().find(|jurisdiction| jurisdiction.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    //adds a jurisdiction taxing every charge at 0%, named so it does not clash with the others
+    pub fn add(&mut self) -> &mut Jurisdiction {
+        let mut name = "New jurisdiction".to_string();
+        let mut number = 1;
+        while self.jurisdiction(&name).is_some() {
+            number += 1;
+            name = format!("New jurisdiction {}", number);
+        }
+        self.jurisdictions.push(Jurisdiction { name, percent: 0.0, taxable: ItemKind::CHARGES.to_vec() });
+        self.jurisdictions.last_mut().unwrap()
+    }
+
+    pub fn validate(&self) -> Result<(), RateCardError> {
+        check_names("tax.jurisdictions", self.jurisdictions.iter().map(|jurisdiction| jurisdiction.name.as_str()))?;
+        match self.jurisdictions.iter().find(|jurisdiction| !(0.0..=100.0).contains(&jurisdiction.percent)) {
+            Some(jurisdiction) => Err(RateCardError::Percent(
+                format!("tax.jurisdictions.{}", jurisdiction.name),
+                jurisdiction.percent,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+//adds the tax line, goes after the discounts
+pub fn apply_tax(quote: &mut Quote, discounts: &[Discount], jurisdiction: &Jurisdiction) {
+    let tax = jurisdiction.taxable_amount(quote, discounts).times(jurisdiction.percent / 100.0);
+    quote.add(ItemKind::Tax, format!("{} tax ({}%)", jurisdiction.name, jurisdiction.percent), 1.0, tax);
+}
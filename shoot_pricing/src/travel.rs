@@ -0,0 +1,143 @@
+//How travel is charged, all from the rates file:
+//  - travel time is billed once however many people go, everyone shares a car: the first
+//    `travel.short_trip_hours` hours of the whole trip at `travel_short_hourly`, anything
+//    beyond at `travel_long_hourly`
+//  - mileage is billed once per leg at `travel.mileage` per mile
+//  - per diem (`travel_per_diem`) covers meals and is billed per person per meal day,
+//    lodging (`travel.lodging`) per person per night
+//  - flights are passed through per person, tolls and parking once per leg
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::Money;
+use crate::quote::*;
+use crate::rates::RateCard;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Leg {
+    pub name: String,
+    pub hours: f32,
+    pub miles: f32,
+    //per person
+    pub flights: Money,
+    pub tolls: Money,
+    pub parking: Money,
+}
+
+impl Default for Leg {
+    fn default() -> Leg {
+        Leg {
+            name: String::new(),
+            hours: 0.0,
+            miles: 0.0,
+            flights: Money::ZERO,
+            tolls: Money::ZERO,
+            parking: Money::ZERO,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Travel {
+    pub legs: Vec<Leg>,
+    pub people: u32,
+    pub nights: u32,
+    pub meal_days: u32,
+}
+
+impl Default for Travel {
+    fn default() -> Travel {
+        Travel { legs: Vec::new(), people: 1, nights: 0, meal_days: 0 }
+    }
+}
+
+impl Travel {
+    pub fn hours(&self) -> f32 {
+        self.legs.iter().map(|leg| leg.hours).sum()
+    }
+
+    //time on the road, the same for everyone in the car
+    pub fn time(&self, rates: &RateCard) -> Quote {
+        let hours = self.hours();
+        let short_hours = hours.min(rates.travel.short_trip_hours);
+        let mut quote = Quote::default();
+        quote.add(ItemKind::Travel, "Travel time", short_hours, rates.travel_short_hourly);
+        quote.add(
+            ItemKind::Travel,
+            format!("Travel time over {} hours", rates.travel.short_trip_hours),
+            hours - short_hours,
+            rates.travel_long_hourly,
+        );
+        quote
+    }
+
+    //what one traveller costs: flights, meals and lodging
+    pub fn per_person(&self, rates: &RateCard) -> Quote {
+        let mut quote = Quote::default();
+        for leg in &self.legs {
+            quote.add(ItemKind::Travel, leg_label("Flights", leg), 1.0, leg.flights);
+        }
+        quote.add(ItemKind::PerDiem, "Per diem", self.meal_days as f32, rates.travel_per_diem);
+        quote.add(ItemKind::Lodging, "Lodging", self.nights as f32, rates.travel.lodging);
+        quote
+    }
+
+    //what the trip costs however many people go: time, mileage, tolls and parking
+    pub fn shared(&self, rates: &RateCard) -> Quote {
+        let mut quote = self.time(rates);
+        quote.items.extend(self.car(rates).items);
+        quote
+    }
+
+    fn car(&self, rates: &RateCard) -> Quote {
+        let mut quote = Quote::default();
+        for leg in &self.legs {
+            quote.add(ItemKind::Travel, leg_label("Mileage", leg), leg.miles, rates.travel.mileage);
+            quote.add(ItemKind::Travel, leg_label("Tolls", leg), 1.0, leg.tolls);
+            quote.add(ItemKind::Travel, leg_label("Parking", leg), 1.0, leg.parking);
+        }
+        quote
+    }
+
+    pub fn calc_price(&self, rates: &RateCard) -> Quote {
+        let people = self.people as f32;
+        let mut quote = self.time(rates);
+        for item in self.per_person(rates).items {
+            let label = match self.people {
+                1 => item.label,
+                _ => format!("{} for {} people", item.label, self.people),
+            };
+            quote.add(item.kind, label, item.quantity * people, item.unit_price);
+        }
+        quote.items.extend(self.car(rates).items);
+        quote
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TravelRates {
+    pub short_trip_hours: f32,
+    pub mileage: Money,
+    pub lodging: Money,
+}
+
+impl Default for TravelRates {
+    fn default() -> TravelRates {
+        TravelRates {
+            short_trip_hours: 2.0,
+            mileage: Money::from_dollars_f64(0.67),
+            lodging: Money::dollars(125),
+        }
+    }
+}
+
+fn leg_label(what: &str, leg: &Leg) -> String {
+    if leg.name.is_empty() {
+        what.to_string()
+    } else {
+        format!("{} ({})", what, leg.name)
+    }
+}
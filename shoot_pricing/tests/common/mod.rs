@@ -0,0 +1,85 @@
+//builders shared by the integration tests, so a new shoot input only has to be added here.
+//each test file uses some of them.
+#![allow(dead_code)]
+
+use shoot_pricing::calc::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::Quote;
+use shoot_pricing::travel::*;
+
+pub fn hourly(hours: f32, photographer: Photographer) -> ShootType {
+    ShootType::Hourly {
+        hours,
+        image_prep: false,
+        assistant_hours: 0.0,
+        use_higher_prep_price: false,
+        use_higher_assistant_price: false,
+        photographer,
+        crew: Vec::new(),
+    }
+}
+
+pub fn half_day(halves: u32, photographer: Photographer) -> ShootType {
+    ShootType::HalfDayBased {
+        halves,
+        image_prep: false,
+        assistant_hours: 0.0,
+        use_higher_prep_price: false,
+        use_higher_assistant_price: false,
+        photographer,
+        crew: Vec::new(),
+    }
+}
+
+pub fn headshot(heads: u32, headshot_type: HeadshotType) -> ShootType {
+    ShootType::Headshot {
+        heads,
+        headshot_type,
+        retouch_level: RetouchLevel::Discount,
+        editing: false,
+        extra_retouched_photos: 0,
+        days: 1,
+    }
+}
+
+//image prep and assistant hours on an hourly or half day shoot
+pub fn with_extras(mut shoot: ShootType, hours: f32, higher_prices: bool) -> ShootType {
+    if let ShootType::Hourly {
+        image_prep,
+        assistant_hours,
+        use_higher_prep_price,
+        use_higher_assistant_price,
+        ..
+    }
+    | ShootType::HalfDayBased {
+        image_prep,
+        assistant_hours,
+        use_higher_prep_price,
+        use_higher_assistant_price,
+        ..
+    } = &mut shoot
+    {
+        *image_prep = true;
+        *assistant_hours = hours;
+        *use_higher_prep_price = higher_prices;
+        *use_higher_assistant_price = higher_prices;
+    }
+    shoot
+}
+
+//a single leg of the given length
+pub fn trip(hours: f32) -> Travel {
+    Travel { legs: vec![Leg { hours, ..Leg::default() }], ..Travel::default() }
+}
+
+pub fn job(shoot: ShootType) -> Job {
+    Job { shoots: vec![shoot], ..Job::default() }
+}
+
+pub fn lines(quote: &Quote) -> Vec<(&str, f32, Money)> {
+    quote.items.iter().map(|item| (item.label.as_str(), item.quantity, item.subtotal)).collect()
+}
+
+pub fn labels(quote: &Quote) -> Vec<&str> {
+    quote.items.iter().map(|item| item.label.as_str()).collect()
+}
@@ -0,0 +1,69 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::compare::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::rates::RateCard;
+
+fn inputs(hours: f32, photographer: Photographer) -> CompareInputs {
+    CompareInputs { hours, photographer, ..CompareInputs::default() }
+}
+
+fn totals(comparison: &Comparison) -> Vec<(ShootKind, Money)> {
+    comparison.options.iter().map(|(kind, quote)| (*kind, quote.total())).collect()
+}
+
+#[test]
+fn compares_hourly_half_day_and_conference_for_the_same_hours() {
+    let comparison = compare(&Job::default(), &inputs(3.0, Photographer::new("Ken")), &RateCard::default());
+    assert_eq!(
+        totals(&comparison),
+        vec![
+            (ShootKind::Hourly, Money::dollars(825)),
+            (ShootKind::HalfDayBased, Money::dollars(1500)),
+            (ShootKind::Conference, Money::dollars(600)),
+        ]
+    );
+    assert_eq!(comparison.cheapest(), Some(ShootKind::Conference));
+}
+
+#[test]
+fn half_days_cover_every_started_block_of_hours() {
+    assert_eq!(inputs(0.0, Photographer::new("Ken")).halves(), 0);
+    assert_eq!(inputs(4.0, Photographer::new("Ken")).halves(), 1);
+    assert_eq!(inputs(4.5, Photographer::new("Ken")).halves(), 2);
+}
+
+#[test]
+fn shared_extras_are_added_to_every_option() {
+    let job = Job { drone: true, expenses: 2, ..Job::default() };
+    let with_extras = compare(&job, &inputs(8.0, Photographer::new("Ken")), &RateCard::default());
+    let without = compare(&Job::default(), &inputs(8.0, Photographer::new("Ken")), &RateCard::default());
+    for ((_, extras), (_, plain)) in totals(&with_extras).into_iter().zip(totals(&without)) {
+        assert_eq!(extras - plain, Money::dollars(150 + 20));
+    }
+}
+
+#[test]
+fn ties_go_to_the_first_option() {
+    let comparison = compare(&Job::default(), &inputs(4.0, Photographer::new("Team")), &RateCard::default());
+    assert_eq!(comparison.cheapest(), Some(ShootKind::Hourly));
+}
+
+#[test]
+fn break_even_is_where_hourly_matches_the_half_days() {
+    let mut rates = RateCard::default();
+    rates.photographers.0[0].hourly = Money::dollars(500);
+    let comparison = compare(&Job::default(), &inputs(8.0, Photographer::new("Ken")), &rates);
+    assert_eq!(comparison.break_even, Some(BreakEven::At(5.0)));
+
+    rates.photographers.0[0].hourly = Money::ZERO;
+    assert_eq!(compare(&Job::default(), &inputs(8.0, Photographer::new("Ken")), &rates).break_even, None);
+}
+
+#[test]
+fn break_even_never_goes_past_the_hours_the_half_days_cover() {
+    //two half days are $2500, which buys more than 9 hours of Ken but only covers 8
+    for hours in [5.0, 8.0] {
+        let comparison = compare(&Job::default(), &inputs(hours, Photographer::new("Ken")), &RateCard::default());
+        assert_eq!(comparison.break_even, Some(BreakEven::HourlyCheaper(8.0)));
+    }
+}
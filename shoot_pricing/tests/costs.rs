@@ -0,0 +1,128 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::cost::*;
+use shoot_pricing::crew::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::tax::Jurisdiction;
+use shoot_pricing::travel::*;
+
+mod common;
+use common::*;
+
+fn rates() -> RateCard {
+    let costs = CostRates {
+        assistant: Money::dollars(25),
+        higher_assistant: Money::dollars(35),
+        retouch_per_image: Money::dollars(4),
+        drone: Money::dollars(100),
+        conference_hourly: Money::dollars(60),
+    };
+    let mut rates = RateCard { costs, ..RateCard::default() };
+    for entry in &mut rates.photographers.0 {
+        if entry.name == "Team" {
+            entry.pay_hourly = Money::dollars(90);
+            entry.pay_half_day = Money::dollars(350);
+        }
+    }
+    for package in &mut rates.headshot_packages {
+        package.pay_hourly = Money::dollars(80);
+    }
+    rates
+}
+
+//with 4 higher rate assistant hours
+fn crewed_half_day(halves: u32, photographer: &str, members: Vec<CrewMember>) -> ShootType {
+    let mut shoot = half_day(halves, Photographer::new(photographer));
+    if let ShootType::HalfDayBased { assistant_hours, use_higher_assistant_price, crew, .. } = &mut shoot {
+        *assistant_hours = 4.0;
+        *use_higher_assistant_price = true;
+        *crew = members;
+    }
+    shoot
+}
+
+#[test]
+fn photographers_and_assistants_are_paid_their_own_rates() {
+    let team = CrewMember { rate: CrewRate::Roster(Photographer::new("Team")), halves: 2, ..CrewMember::default() };
+    let assistant = CrewMember { role: CrewRole::Assistant, rate: CrewRate::Assistant, hours: 8.0, ..CrewMember::default() };
+    let job = Job { drone: true, ..job(crewed_half_day(2, "Ken", vec![team, assistant])) };
+    assert_eq!(
+        lines(&job_cost(&job, &rates())),
+        vec![
+            ("Higher rate assistant pay", 4.0, Money::dollars(140)),
+            ("Team half day pay", 2.0, Money::dollars(700)),
+            ("Assistant pay", 8.0, Money::dollars(200)),
+            ("Drone operator", 1.0, Money::dollars(100)),
+        ]
+    );
+}
+
+#[test]
+fn headshot_photographers_are_paid_for_every_hour_on_site() {
+    let mut shoot = headshot(24, HeadshotType::new("Team"));
+    if let ShootType::Headshot { extra_retouched_photos, .. } = &mut shoot {
+        *extra_retouched_photos = 6;
+    }
+    assert_eq!(
+        lines(&job_cost(&job(shoot), &rates())),
+        vec![
+            ("Photographer pay", 3.0, Money::dollars(240)),
+            ("Assistant pay", 3.0, Money::dollars(75)),
+            ("Retoucher", 30.0, Money::dollars(120)),
+        ]
+    );
+}
+
+#[test]
+fn headshot_margin_counts_the_photographer() {
+    let job = job(headshot(12, HeadshotType::new("Large")));
+    let rates = rates();
+    let margin = GrossMargin::new(&job, &rates, &job.calc_price(&rates));
+    //two hours on site with two assistants, and twelve headshots to retouch
+    assert_eq!(margin.cost, Money::dollars(2 * 80 + 4 * 25 + 12 * 4));
+}
+
+#[test]
+fn conference_photographers_are_paid_by_the_hour() {
+    let shoot = ShootType::Conference { hours: 3.0, extra_cost: Money::dollars(20) };
+    assert_eq!(
+        lines(&job_cost(&job(shoot), &rates())),
+        vec![("Photographer pay", 3.0, Money::dollars(180)), ("Retouching / extra costs", 1.0, Money::dollars(20))]
+    );
+}
+
+#[test]
+fn travel_expenses_are_paid_out_but_travel_time_is_not() {
+    let leg = Leg { hours: 3.0, miles: 100.0, tolls: Money::dollars(12), ..Leg::default() };
+    let travel = Travel { legs: vec![leg], people: 2, nights: 1, ..Travel::default() };
+    let job = Job { shoots: Vec::new(), travel, ..Job::default() };
+    let rates = rates();
+    //mileage, tolls and two nights of lodging
+    assert_eq!(lines(&job_cost(&job, &rates)), vec![("Travel expenses", 1.0, Money::dollars(67 + 12 + 250))]);
+}
+
+#[test]
+fn margin_is_taken_on_the_price_before_tax() {
+    let mut rates = rates();
+    rates.tax.jurisdictions.push(Jurisdiction {
+        name: "State".to_string(),
+        percent: 10.0,
+        taxable: shoot_pricing::quote::ItemKind::CHARGES.to_vec(),
+    });
+    let job = Job { jurisdiction: "State".to_string(), ..job(crewed_half_day(1, "Team", Vec::new())) };
+    let quote = job.calc_price(&rates);
+    let margin = GrossMargin::new(&job, &rates, &quote);
+    //a $600 half day and 4 higher rate assistant hours at $50
+    assert_eq!(margin.price, Money::dollars(800));
+    assert_eq!(margin.cost, Money::dollars(350 + 140));
+    assert_eq!(margin.amount(), Money::dollars(310));
+    assert!((margin.percent().unwrap() - 38.75).abs() < 0.001);
+}
+
+#[test]
+fn free_jobs_have_no_margin_percentage() {
+    let job = Job { shoots: Vec::new(), ..Job::default() };
+    let rates = rates();
+    let margin = GrossMargin::new(&job, &rates, &job.calc_price(&rates));
+    assert_eq!(margin.percent(), None);
+}
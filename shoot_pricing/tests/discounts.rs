@@ -0,0 +1,126 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::discount::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::{ItemKind, Quote};
+use shoot_pricing::rates::RateCard;
+
+mod common;
+use common::*;
+
+fn discounted(hours: f32, discounts: Vec<Discount>) -> Job {
+    Job { discounts, ..job(hourly(hours, Photographer::new("Ken"))) }
+}
+
+fn fixed(name: &str, dollars: i64, items: Vec<ItemKind>) -> Discount {
+    Discount { name: name.to_string(), amount: DiscountAmount::Fixed(Money::dollars(dollars)), items }
+}
+
+fn discount_lines(quote: &Quote) -> Vec<(&str, Money)> {
+    quote
+        .items
+        .iter()
+        .filter(|item| item.kind == ItemKind::Discount)
+        .map(|item| (item.label.as_str(), item.subtotal))
+        .collect()
+}
+
+#[test]
+fn percentage_off_the_whole_quote_is_a_negative_line_item() {
+    let quote = discounted(4.0, vec![Discount::percent("Repeat client", 10.0)]).calc_price(&RateCard::default());
+    assert_eq!(discount_lines(&quote), vec![("Repeat client", Money::dollars(-110))]);
+    assert_eq!(quote.total(), Money::dollars(990));
+}
+
+#[test]
+fn item_discounts_only_come_off_their_items() {
+    let mut job = discounted(3.0, vec![fixed("Free drone", 200, vec![ItemKind::Drone])]);
+    job.drone = true;
+    let quote = job.calc_price(&RateCard::default());
+    assert_eq!(discount_lines(&quote), vec![("Free drone", Money::dollars(-150))]);
+    assert_eq!(quote.total(), Money::dollars(825));
+
+    let job = Job { discounts: vec![Discount { items: vec![ItemKind::Drone], ..Discount::percent("Drone", 50.0) }], ..job };
+    assert_eq!(job.calc_price(&RateCard::default()).total(), Money::dollars(825 + 75));
+}
+
+#[test]
+fn discounts_never_take_the_total_below_zero() {
+    let quote = discounted(3.0, vec![fixed("Gift card", 500, Vec::new()), fixed("Gift card", 500, Vec::new())])
+        .calc_price(&RateCard::default());
+    assert_eq!(discount_lines(&quote), vec![("Gift card", Money::dollars(-500)), ("Gift card", Money::dollars(-325))]);
+    assert_eq!(quote.total(), Money::ZERO);
+
+    let quote = discounted(3.0, vec![Discount::percent("Too much", 250.0)]).calc_price(&RateCard::default());
+    assert_eq!(quote.total(), Money::ZERO);
+}
+
+#[test]
+fn percentages_are_taken_from_the_undiscounted_items() {
+    let quote = discounted(4.0, vec![Discount::percent("A", 10.0), Discount::percent("B", 10.0)])
+        .calc_price(&RateCard::default());
+    assert_eq!(discount_lines(&quote), vec![("A", Money::dollars(-110)), ("B", Money::dollars(-110))]);
+}
+
+#[test]
+fn stacked_discounts_never_take_an_item_below_zero() {
+    let discounts = vec![Discount::percent("Half off", 50.0), fixed("Free drone", 200, vec![ItemKind::Drone])];
+    let job = Job { drone: true, ..discounted(3.0, discounts) };
+    let quote = job.calc_price(&RateCard::default());
+    //half of the drone is already off, only the other half is left for the free drone
+    assert_eq!(discount_lines(&quote), vec![("Half off", Money::from_dollars_f64(-487.5)), ("Free drone", Money::dollars(-75))]);
+    assert_eq!(quote.total(), Money::from_dollars_f64(412.5));
+}
+
+#[test]
+fn client_rates_come_from_the_rate_card() {
+    let job = Job { client_rate: ClientRate::Nonprofit, ..discounted(3.0, Vec::new()) };
+    let quote = job.calc_price(&RateCard::default());
+    assert_eq!(discount_lines(&quote), vec![("Nonprofit rate (10%)", Money::from_dollars_f64(-82.5))]);
+
+    let student = Job { client_rate: ClientRate::Student, ..job };
+    assert_eq!(student.calc_price(&RateCard::default()).total(), Money::from_dollars_f64(701.25));
+}
+
+#[test]
+fn promo_codes_are_looked_up_ignoring_case() {
+    let mut rates = RateCard::default();
+    rates.discounts.promo_codes.push(PromoCode { code: "SPRING ".to_string(), discount: fixed("Spring promo", 100, Vec::new()) });
+
+    let job = Job { promo_code: " spring ".to_string(), ..discounted(3.0, Vec::new()) };
+    assert_eq!(discount_lines(&job.calc_price(&rates)), vec![("Spring promo", Money::dollars(-100))]);
+
+    let unknown = Job { promo_code: "WINTER".to_string(), ..job };
+    assert!(discount_lines(&unknown.calc_price(&rates)).is_empty());
+}
+
+#[test]
+fn multi_day_packages_use_the_biggest_tier_reached() {
+    let mut rates = RateCard::default();
+    rates.discounts.multi_day = vec![
+        MultiDayDiscount { min_days: 2, percent: 5.0 },
+        MultiDayDiscount { min_days: 3, percent: 10.0 },
+    ];
+    let half_days = |halves| Job { shoots: vec![half_day(halves, Photographer::new("Ken"))], ..Job::default() };
+    assert!(discount_lines(&half_days(2).calc_price(&rates)).is_empty());
+    assert_eq!(discount_lines(&half_days(3).calc_price(&rates))[0].0, "2 day package (5%)");
+    assert_eq!(discount_lines(&half_days(6).calc_price(&rates))[0].0, "3 day package (10%)");
+}
+
+#[test]
+fn shoot_days_count_every_shoot() {
+    let mut two_day_headshots = headshot(10, HeadshotType::new("Team"));
+    if let ShootType::Headshot { days, .. } = &mut two_day_headshots {
+        *days = 2;
+    }
+    let job = Job {
+        shoots: vec![
+            hourly(0.0, Photographer::new("Ken")),
+            hourly(2.0, Photographer::new("Ken")),
+            half_day(3, Photographer::new("Ken")),
+            two_day_headshots,
+            ShootType::Conference { hours: 5.0, extra_cost: Money::ZERO },
+        ],
+        ..Job::default()
+    };
+    assert_eq!(job.shoot_days(), 1 + 2 + 2 + 1);
+}
@@ -0,0 +1,80 @@
+//every tests/scenarios/*.toml is a Job priced with the built-in rates and compared
+//against the .expected file next to it. Run with UPDATE_GOLDEN=1 to accept new prices.
+
+use std::path::{Path, PathBuf};
+
+use shoot_pricing::calc::Job;
+use shoot_pricing::quote::Quote;
+use shoot_pricing::rates::RateCard;
+
+fn render(quote: &Quote) -> String {
+    let mut text = String::new();
+    for item in &quote.items {
+        text += &format!("{} | {} x {} = {}\n", item.label, item.quantity, item.unit_price, item.subtotal);
+    }
+    text += &format!("Total {}\n", quote.total());
+    text
+}
+
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut text = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => text += &format!("  {}\n", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    text += &format!("- {}\n", e);
+                }
+                if let Some(a) = a {
+                    text += &format!("+ {}\n", a);
+                }
+            }
+        }
+    }
+    text
+}
+
+fn scenarios() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("scenarios");
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn scenarios_match_expected_prices() {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let rates = RateCard::default();
+    let mut failures = Vec::new();
+
+    let paths = scenarios();
+    assert!(!paths.is_empty(), "no scenarios found");
+    for path in paths {
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let job: Job = toml::from_str(&std::fs::read_to_string(&path).unwrap())
+            .unwrap_or_else(|err| panic!("{} is not a valid job: {}", name, err));
+        let actual = render(&job.calc_price(&rates));
+        let expected_path = path.with_extension("expected");
+
+        if update {
+            std::fs::write(&expected_path, &actual).unwrap();
+            continue;
+        }
+        let expected = std::fs::read_to_string(&expected_path).unwrap_or_default();
+        if expected != actual {
+            failures.push(format!("{}:\n{}", name, diff(&expected, &actual)));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "published prices changed, rerun with UPDATE_GOLDEN=1 if this is deliberate\n\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,73 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::payment::*;
+use shoot_pricing::rates::RateCard;
+
+mod common;
+use common::*;
+
+fn schedule(job: &Job) -> Vec<(String, Due, Money)> {
+    let rates = RateCard::default();
+    let quote = job.calc_price(&rates);
+    job.schedule(&rates, &quote)
+        .unwrap()
+        .into_iter()
+        .map(|payment| (payment.name, payment.due, payment.amount))
+        .collect()
+}
+
+#[test]
+fn small_jobs_are_paid_in_full_on_delivery() {
+    assert_eq!(
+        schedule(&job(hourly(3.0, Photographer::new("Ken")))),
+        vec![("Payment on delivery".to_string(), Due::ShootDay(14), Money::dollars(825))]
+    );
+}
+
+#[test]
+fn half_day_and_large_headshot_jobs_take_a_deposit() {
+    assert_eq!(
+        schedule(&job(half_day(3, Photographer::new("Ken")))),
+        vec![
+            ("Deposit".to_string(), Due::Booking, Money::dollars(2000)),
+            ("Balance on delivery".to_string(), Due::ShootDay(14), Money::dollars(2000)),
+        ]
+    );
+    let rates = RateCard::default();
+    assert!(requires_deposit(&job(headshot(30, HeadshotType::new("Large"))), &rates));
+    assert!(!requires_deposit(&job(headshot(30, HeadshotType::new("Team"))), &rates));
+    assert!(!requires_deposit(&job(half_day(0, Photographer::new("Ken"))), &rates));
+}
+
+#[test]
+fn custom_milestones_add_up_to_the_total_to_the_cent() {
+    let third = |name: &str, percent, due| Milestone { name: name.to_string(), percent, due };
+    let job = Job {
+        payment_plan: PaymentPlan::Custom(vec![
+            third("Booking", 33.33, Due::Booking),
+            third("Week before", 33.33, Due::ShootDay(-7)),
+            third("Delivery", 33.34, Due::ShootDay(14)),
+        ]),
+        expenses: 1,
+        ..job(half_day(1, Photographer::new("Ken")))
+    };
+    let payments = schedule(&job);
+    assert_eq!(payments[0].2, Money::from_dollars_f64(503.28));
+    let total: Money = payments.iter().map(|payment| payment.2).sum();
+    assert_eq!(total, Money::dollars(1510));
+}
+
+#[test]
+fn milestones_must_cover_the_whole_total() {
+    let milestones = vec![Milestone { name: "Deposit".to_string(), percent: 40.0, due: Due::Booking }];
+    let err = payment_schedule(&milestones, Money::dollars(100)).unwrap_err();
+    assert_eq!(err.to_string(), "payments add up to 40% instead of 100%");
+}
+
+#[test]
+fn due_dates_read_relative_to_the_shoot() {
+    assert_eq!(Due::Booking.to_string(), "on booking");
+    assert_eq!(Due::ShootDay(0).to_string(), "on the shoot day");
+    assert_eq!(Due::ShootDay(14).to_string(), "14 days after the shoot");
+    assert_eq!(Due::ShootDay(-7).to_string(), "7 days before the shoot");
+}
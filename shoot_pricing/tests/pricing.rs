@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+
+use shoot_pricing::calc::*;
+use shoot_pricing::crew::*;
+use shoot_pricing::headshot::HeadshotPackage;
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::Quote;
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::travel::*;
+
+mod common;
+use common::*;
+
+fn price(job: &Job) -> Quote {
+    job.calc_price(&RateCard::default())
+}
+
+fn total(job: &Job) -> Money {
+    price(job).total()
+}
+
+#[test]
+fn headshot_hours_add_a_setup_hour_to_every_started_dozen() {
+    let rates = RateCard::default();
+    let large = rates.headshot_package("Large").unwrap();
+    assert_eq!(large.hours(0), 1.0);
+    assert_eq!(large.hours(1), 2.0);
+    assert_eq!(large.hours(12), 2.0);
+    assert_eq!(large.hours(13), 3.0);
+    assert_eq!(large.hours(24), 3.0);
+    assert_eq!(large.hours(25), 4.0);
+}
+
+#[test]
+fn hourly_uses_the_photographer_rate() {
+    assert_eq!(total(&job(hourly(3.0, Photographer::new("Ken")))), Money::dollars(825));
+    assert_eq!(total(&job(hourly(3.0, Photographer::new("Colin")))), Money::dollars(675));
+    assert_eq!(total(&job(hourly(3.0, Photographer::new("Team")))), Money::dollars(450));
+    assert_eq!(total(&job(hourly(0.5, Photographer::new("Ken")))), Money::from_dollars_f64(137.5));
+}
+
+#[test]
+fn hourly_extras() {
+    let shoot = ShootType::Hourly {
+        hours: 2.0,
+        image_prep: true,
+        assistant_hours: 2.0,
+        use_higher_prep_price: false,
+        use_higher_assistant_price: false,
+        photographer: Photographer::new("Ken"),
+        crew: Vec::new(),
+    };
+    let quote = price(&job(shoot));
+    assert_eq!(labels(&quote), ["Ken hourly", "Image prep", "Assistant hours"]);
+    assert_eq!(quote.total(), Money::dollars(550 + 50 + 80));
+
+    let shoot = ShootType::Hourly {
+        hours: 2.0,
+        image_prep: true,
+        assistant_hours: 2.0,
+        use_higher_prep_price: true,
+        use_higher_assistant_price: true,
+        photographer: Photographer::new("Ken"),
+        crew: Vec::new(),
+    };
+    assert_eq!(total(&job(shoot)), Money::dollars(550 + 100 + 100));
+}
+
+#[test]
+fn higher_prep_price_without_image_prep_is_free() {
+    let shoot = ShootType::Hourly {
+        hours: 1.0,
+        image_prep: false,
+        assistant_hours: 0.0,
+        use_higher_prep_price: true,
+        use_higher_assistant_price: false,
+        photographer: Photographer::new("Ken"),
+        crew: Vec::new(),
+    };
+    assert_eq!(total(&job(shoot)), Money::dollars(275));
+}
+
+#[test]
+fn half_days_split_into_first_and_second_halves() {
+    assert_eq!(total(&job(half_day(0, Photographer::new("Ken")))), Money::ZERO);
+    assert_eq!(total(&job(half_day(1, Photographer::new("Ken")))), Money::dollars(1500));
+    assert_eq!(total(&job(half_day(2, Photographer::new("Ken")))), Money::dollars(2500));
+    assert_eq!(total(&job(half_day(3, Photographer::new("Ken")))), Money::dollars(4000));
+    assert_eq!(total(&job(half_day(4, Photographer::new("Ken")))), Money::dollars(5000));
+    assert_eq!(total(&job(half_day(3, Photographer::new("Team")))), Money::dollars(1800));
+}
+
+#[test]
+fn half_day_odd_split_has_more_first_halves() {
+    let quote = price(&job(half_day(5, Photographer::new("Colin"))));
+    assert_eq!(quote.items[0].label, "Colin first half day");
+    assert_eq!(quote.items[0].quantity, 3.0);
+    assert_eq!(quote.items[1].label, "Colin second half day");
+    assert_eq!(quote.items[1].quantity, 2.0);
+}
+
+#[test]
+fn large_headshot() {
+    let shoot = ShootType::Headshot {
+        heads: 30,
+        headshot_type: HeadshotType::new("Large"),
+        retouch_level: RetouchLevel::Discount,
+        editing: true,
+        extra_retouched_photos: 3,
+        days: 2,
+    };
+    //4 hours with two assistants, retouching, extra photos and two days of editing
+    assert_eq!(total(&job(shoot)), Money::dollars(1100 + 320 + 300 + 60 + 200));
+}
+
+#[test]
+fn team_headshot() {
+    let shoot = ShootType::Headshot {
+        heads: 12,
+        headshot_type: HeadshotType::new("Team"),
+        retouch_level: RetouchLevel::Corporate,
+        editing: false,
+        extra_retouched_photos: 0,
+        days: 1,
+    };
+    assert_eq!(total(&job(shoot)), Money::dollars(400 + 80 + 240));
+}
+
+#[test]
+fn headshot_hours_step_at_thirteen_heads() {
+    let twelve = total(&job(headshot(12, HeadshotType::new("Team"))));
+    let thirteen = total(&job(headshot(13, HeadshotType::new("Team"))));
+    //one more hour of photographer and assistant plus one more retouch
+    assert_eq!(thirteen - twelve, Money::dollars(200 + 40 + 10));
+}
+
+#[test]
+fn headshot_with_nobody_still_books_the_setup_hour() {
+    assert_eq!(total(&job(headshot(0, HeadshotType::new("Large")))), Money::dollars(275 + 80));
+}
+
+#[test]
+fn small_headshot_session_covers_the_first_five_people() {
+    assert_eq!(total(&job(headshot(0, HeadshotType::new("Small")))), Money::dollars(400));
+    assert_eq!(total(&job(headshot(5, HeadshotType::new("Small")))), Money::dollars(400));
+    assert_eq!(total(&job(headshot(7, HeadshotType::new("Small")))), Money::dollars(400 + 2 * 50));
+}
+
+fn small_session(heads: u32, retouch: RetouchLevel, on_site_editing: bool, session_days: u32) -> ShootType {
+    let mut shoot = headshot(heads, HeadshotType::new("Small"));
+    if let ShootType::Headshot { retouch_level, editing, days, .. } = &mut shoot {
+        *retouch_level = retouch;
+        *editing = on_site_editing;
+        *days = session_days;
+    }
+    shoot
+}
+
+#[test]
+fn small_headshot_extra_days_and_better_retouching_are_charged() {
+    //the extra day and the difference between corporate and the included discount retouching
+    assert_eq!(total(&job(small_session(4, RetouchLevel::Corporate, false, 2))), Money::dollars(400 + 400 + 4 * 10));
+    assert_eq!(total(&job(small_session(4, RetouchLevel::Student, false, 1))), Money::dollars(400));
+}
+
+#[test]
+fn small_headshot_does_not_offer_on_site_editing() {
+    assert_eq!(total(&job(small_session(4, RetouchLevel::Discount, true, 1))), Money::dollars(400));
+}
+
+#[test]
+fn retouch_levels() {
+    let rates = RateCard::default();
+    assert_eq!(RetouchLevel::Student.get_price_per(&rates), Money::dollars(5));
+    assert_eq!(RetouchLevel::Discount.get_price_per(&rates), Money::dollars(10));
+    assert_eq!(RetouchLevel::Corporate.get_price_per(&rates), Money::dollars(20));
+    assert_eq!(RetouchLevel::Full.get_price_per(&rates), Money::dollars(50));
+}
+
+#[test]
+fn conference() {
+    let shoot = ShootType::Conference { hours: 2.5, extra_cost: Money::from_dollars_f64(12.34) };
+    assert_eq!(total(&job(shoot)), Money::from_dollars_f64(512.34));
+}
+
+#[test]
+fn drone_expenses_and_travel_are_added_to_every_shoot_type() {
+    for shoot in [
+        hourly(1.0, Photographer::new("Ken")),
+        half_day(1, Photographer::new("Ken")),
+        headshot(10, HeadshotType::new("Large")),
+        headshot(10, HeadshotType::new("Small")),
+        ShootType::Conference { hours: 1.0, extra_cost: Money::ZERO },
+    ] {
+        let plain = job(shoot.clone());
+        let extras = Job { drone: true, expenses: 3, travel: trip(1.0), ..job(shoot) };
+        assert_eq!(total(&extras) - total(&plain), Money::dollars(150 + 30 + 75));
+    }
+}
+
+#[test]
+fn several_shoots_share_extras() {
+    let job = Job {
+        shoots: vec![headshot(12, HeadshotType::new("Team")), ShootType::Conference { hours: 2.0, extra_cost: Money::ZERO }],
+        drone: true,
+        ..Job::default()
+    };
+    let quote = price(&job);
+    assert_eq!(
+        labels(&quote),
+        ["1. Team headshot hours", "1. Assistant hours", "1. Retouching (Discount)", "2. Conference hours", "Drone"]
+    );
+    assert_eq!(quote.total(), Money::dollars(400 + 80 + 120 + 400 + 150));
+}
+
+#[test]
+fn empty_job_is_free() {
+    let job = Job { shoots: Vec::new(), ..Job::default() };
+    assert!(price(&job).items.is_empty());
+    assert_eq!(total(&job), Money::ZERO);
+}
+
+#[test]
+fn zero_items_are_left_off_the_breakdown() {
+    let quote = price(&job(hourly(0.0, Photographer::new("Ken"))));
+    assert!(quote.items.is_empty());
+}
+
+#[test]
+fn line_item_subtotals_add_up_to_the_total() {
+    let job = Job {
+        shoots: vec![hourly(1.5, Photographer::new("Colin")), half_day(3, Photographer::new("Team"))],
+        drone: true,
+        expenses: 7,
+        travel: Travel { people: 2, nights: 2, meal_days: 3, ..trip(5.5) },
+        ..Job::default()
+    };
+    let quote = price(&job);
+    let sum = quote.items.iter().map(|item| item.unit_price.times(item.quantity)).sum::<Money>();
+    assert_eq!(sum, quote.total());
+}
+
+#[test]
+fn every_kind_is_listed_once_and_builds_its_own_variant() {
+    for (index, kind) in ShootKind::ALL.into_iter().enumerate() {
+        //no wildcard so a new variant has to be added here and to ALL
+        let expected = match kind {
+            ShootKind::Hourly => 0,
+            ShootKind::HalfDayBased => 1,
+            ShootKind::Headshot => 2,
+            ShootKind::Conference => 3,
+        };
+        assert_eq!(index, expected);
+        assert_eq!(kind.default_shoot(&RateCard::default()).kind(), kind);
+        assert_eq!(kind.default_shoot(&RateCard::default()).to_string(), kind.to_string());
+    }
+}
+
+#[test]
+fn shoot_equality_compares_inputs_for_every_kind() {
+    for kind in ShootKind::ALL {
+        assert_eq!(kind.default_shoot(&RateCard::default()), kind.default_shoot(&RateCard::default()));
+    }
+    assert_ne!(hourly(1.0, Photographer::new("Ken")), hourly(2.0, Photographer::new("Ken")));
+    assert_ne!(
+        ShootType::Conference { hours: 1.0, extra_cost: Money::ZERO },
+        ShootType::Conference { hours: 1.0, extra_cost: Money::dollars(5) }
+    );
+    assert_ne!(ShootKind::Conference.default_shoot(&RateCard::default()), ShootKind::Hourly.default_shoot(&RateCard::default()));
+}
+
+#[test]
+fn switching_kinds_back_restores_the_entered_inputs() {
+    let mut remembered = HashMap::new();
+    let mut shoot = headshot(30, HeadshotType::new("Team"));
+    shoot.switch_kind(ShootKind::Conference, &mut remembered, &RateCard::default());
+    assert_eq!(shoot, ShootKind::Conference.default_shoot(&RateCard::default()));
+    shoot.switch_kind(ShootKind::Headshot, &mut remembered, &RateCard::default());
+    assert_eq!(shoot, headshot(30, HeadshotType::new("Team")));
+}
+
+#[test]
+fn switching_between_hourly_and_half_day_carries_shared_inputs() {
+    let mut remembered = HashMap::new();
+    let mut shoot = ShootType::Hourly {
+        hours: 3.0,
+        image_prep: true,
+        assistant_hours: 2.0,
+        use_higher_prep_price: true,
+        use_higher_assistant_price: false,
+        photographer: Photographer::new("Colin"),
+        crew: Vec::new(),
+    };
+    shoot.switch_kind(ShootKind::HalfDayBased, &mut remembered, &RateCard::default());
+    assert_eq!(
+        shoot,
+        ShootType::HalfDayBased {
+            halves: 0,
+            image_prep: true,
+            assistant_hours: 2.0,
+            use_higher_prep_price: true,
+            use_higher_assistant_price: false,
+            photographer: Photographer::new("Colin"),
+            crew: Vec::new(),
+        }
+    );
+
+    if let ShootType::HalfDayBased { photographer, .. } = &mut shoot {
+        *photographer = Photographer::new("Team");
+    }
+    shoot.switch_kind(ShootKind::Hourly, &mut remembered, &RateCard::default());
+    let ShootType::Hourly { hours, photographer, .. } = shoot else { panic!("expected an hourly shoot") };
+    assert_eq!(hours, 3.0);
+    assert_eq!(photographer, Photographer::new("Team"));
+}
+
+#[test]
+fn photographers_are_priced_from_the_roster() {
+    let mut rates = RateCard::default();
+    let added = rates.photographers.add();
+    added.name = "Sam".to_string();
+    added.hourly = Money::dollars(180);
+    rates.photographers.0[0].active = false;
+
+    let shoot = |name: &str| job(hourly(2.0, Photographer::new(name))).calc_price(&rates).total();
+    assert_eq!(shoot("sam"), Money::dollars(360));
+    //retired photographers keep pricing the quotes that name them
+    assert_eq!(shoot("Ken"), Money::dollars(550));
+    assert_eq!(shoot("Nobody"), Money::ZERO);
+    let active: Vec<&str> = rates.photographers.active().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(active, ["Colin", "Team", "Sam"]);
+    //new shoots start on the first photographer still working, spelled the roster's way
+    assert_eq!(ShootKind::Hourly.default_shoot(&rates), hourly(0.0, Photographer::new("Colin")));
+    assert_eq!(labels(&job(hourly(2.0, Photographer::new("sam"))).calc_price(&rates)), ["Sam hourly"]);
+}
+
+#[test]
+fn crew_members_are_priced_per_person() {
+    let member = |role, rate, hours| CrewMember { role, rate, hours, ..CrewMember::default() };
+    let mut shoot = hourly(3.0, Photographer::new("Ken"));
+    if let ShootType::Hourly { crew, .. } = &mut shoot {
+        *crew = vec![
+            member(CrewRole::Photographer, CrewRate::Roster(Photographer::new("Team")), 2.0),
+            member(CrewRole::Assistant, CrewRate::Roster(Photographer::new("Colin")), 1.0),
+            member(CrewRole::Assistant, CrewRate::Assistant, 3.0),
+        ];
+    }
+    let quote = price(&job(shoot.clone()));
+    assert_eq!(labels(&quote), ["Ken hourly", "Team hourly", "Colin hourly as assistant", "Assistant hours"]);
+    assert_eq!(quote.total(), Money::dollars(825 + 300 + 225 + 120));
+    assert_eq!(shoot.description(), "Hourly shoot with Ken and 3 more crew, 3 hours");
+    let names: Vec<String> = shoot.photographers().iter().map(|photographer| photographer.to_string()).collect();
+    assert_eq!(names, ["Ken", "Team", "Colin"]);
+}
+
+#[test]
+fn new_headshot_packages_price_and_describe_from_the_same_numbers() {
+    let mut rates = RateCard::default();
+    rates.headshot_packages.push(HeadshotPackage {
+        hourly: Money::dollars(350),
+        heads_per_hour: 6,
+        setup_hours: 1.5,
+        assistants: 1.0,
+        features: "outdoor locations".to_string(),
+        ..HeadshotPackage::new("Executive")
+    });
+    let shoot = headshot(8, HeadshotType::new("executive"));
+    let quote = job(shoot.clone()).calc_price(&rates);
+    assert_eq!(quote.items[0].label, "Executive headshot hours");
+    assert_eq!(quote.items[0].quantity, 3.5);
+    assert_eq!(quote.items[1].quantity, 3.5);
+    assert_eq!(
+        shoot.features(&rates).unwrap(),
+        "Features:\noutdoor locations\n6/people per hour\n\
+         for up to 2 photo hours (plus 1.5 extra hours on site for set-up & teardown)"
+    );
+}
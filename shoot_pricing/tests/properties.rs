@@ -0,0 +1,127 @@
+use proptest::prelude::*;
+use shoot_pricing::calc::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::travel::*;
+
+mod common;
+use common::*;
+
+fn photographer() -> impl Strategy<Value = Photographer> {
+    prop_oneof![Just(Photographer::new("Ken")), Just(Photographer::new("Colin")), Just(Photographer::new("Team"))]
+}
+
+fn headshot_type() -> impl Strategy<Value = HeadshotType> {
+    prop_oneof![Just(HeadshotType::new("Large")), Just(HeadshotType::new("Team")), Just(HeadshotType::new("Small"))]
+}
+
+fn retouch_level() -> impl Strategy<Value = RetouchLevel> {
+    prop_oneof![
+        Just(RetouchLevel::Student),
+        Just(RetouchLevel::Discount),
+        Just(RetouchLevel::Corporate),
+        Just(RetouchLevel::Full),
+    ]
+}
+
+fn retouched_headshot(heads: u32, headshot_type: HeadshotType, retouch: RetouchLevel) -> ShootType {
+    let mut shoot = headshot(heads, headshot_type);
+    if let ShootType::Headshot { retouch_level, editing, extra_retouched_photos, .. } = &mut shoot {
+        *retouch_level = retouch;
+        *editing = true;
+        *extra_retouched_photos = 2;
+    }
+    shoot
+}
+
+fn total(shoot: ShootType, travel_hours: f32) -> Money {
+    let job = Job { shoots: vec![shoot], travel: trip(travel_hours), ..Job::default() };
+    job.calc_price(&RateCard::default()).total()
+}
+
+proptest! {
+    #[test]
+    fn totals_are_never_negative(
+        hours in 0.0f32..200.0,
+        halves in 0u32..20,
+        heads in 0u32..500,
+        travel_hours in 0.0f32..20.0,
+        photographer in photographer(),
+        headshot_type in headshot_type(),
+        retouch_level in retouch_level(),
+    ) {
+        prop_assert!(total(with_extras(hourly(hours, photographer.clone()), hours, false), travel_hours) >= Money::ZERO);
+        prop_assert!(total(with_extras(half_day(halves, photographer), hours, true), travel_hours) >= Money::ZERO);
+        prop_assert!(total(retouched_headshot(heads, headshot_type, retouch_level), travel_hours) >= Money::ZERO);
+        let conference = ShootType::Conference { hours, extra_cost: Money::ZERO };
+        prop_assert!(total(conference, travel_hours) >= Money::ZERO);
+    }
+
+    #[test]
+    fn hourly_is_monotone_in_hours(
+        hours in 0.0f32..100.0,
+        more in 0.0f32..10.0,
+        assistant_hours in 0.0f32..20.0,
+        photographer in photographer(),
+    ) {
+        let fewer = total(with_extras(hourly(hours, photographer.clone()), assistant_hours, false), 0.0);
+        let longer = total(with_extras(hourly(hours + more, photographer.clone()), assistant_hours, false), 0.0);
+        let assisted = total(with_extras(hourly(hours, photographer), assistant_hours + more, false), 0.0);
+        prop_assert!(longer >= fewer);
+        prop_assert!(assisted >= fewer);
+    }
+
+    #[test]
+    fn half_day_is_monotone_in_halves(halves in 0u32..40, photographer in photographer()) {
+        let shoot = |halves| half_day(halves, photographer.clone());
+        prop_assert!(total(shoot(halves + 1), 0.0) >= total(shoot(halves), 0.0));
+    }
+
+    #[test]
+    fn conference_is_monotone_in_hours(hours in 0.0f32..100.0, more in 0.0f32..10.0) {
+        let shoot = |hours| ShootType::Conference { hours, extra_cost: Money::ZERO };
+        prop_assert!(total(shoot(hours + more), 0.0) >= total(shoot(hours), 0.0));
+    }
+
+    #[test]
+    fn headshot_is_monotone_in_heads(
+        heads in 0u32..500,
+        more in 0u32..50,
+        headshot_type in headshot_type(),
+        retouch_level in retouch_level(),
+    ) {
+        let fewer = total(retouched_headshot(heads, headshot_type.clone(), retouch_level.clone()), 0.0);
+        let more = total(retouched_headshot(heads + more, headshot_type, retouch_level), 0.0);
+        prop_assert!(more >= fewer);
+    }
+
+    #[test]
+    fn travel_is_monotone_in_every_input(
+        hours in 0.0f32..20.0,
+        miles in 0.0f32..500.0,
+        people in 1u32..5,
+        nights in 0u32..10,
+        meal_days in 0u32..10,
+    ) {
+        let rates = RateCard::default();
+        let base = Travel {
+            legs: vec![Leg { hours, miles, ..Leg::default() }],
+            people,
+            nights,
+            meal_days,
+        };
+        let price = |travel: &Travel| travel.calc_price(&rates).total();
+        let more_hours = Travel { legs: vec![Leg { hours: hours + 0.5, miles, ..Leg::default() }], ..base.clone() };
+        let more_miles = Travel { legs: vec![Leg { hours, miles: miles + 10.0, ..Leg::default() }], ..base.clone() };
+        let another_leg = Travel { legs: vec![base.legs[0].clone(), Leg { hours: 1.0, ..Leg::default() }], ..base.clone() };
+        prop_assert!(price(&more_hours) >= price(&base));
+        prop_assert!(price(&more_miles) >= price(&base));
+        prop_assert!(price(&another_leg) >= price(&base));
+        let more_people = Travel { people: people + 1, ..base.clone() };
+        let more_nights = Travel { nights: nights + 1, ..base.clone() };
+        let more_meals = Travel { meal_days: meal_days + 1, ..base.clone() };
+        prop_assert!(price(&more_people) >= price(&base));
+        prop_assert!(price(&more_nights) >= price(&base));
+        prop_assert!(price(&more_meals) >= price(&base));
+    }
+}
@@ -0,0 +1,57 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::travel::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn quotes_saved_with_a_single_shoot_still_load() {
+    let job: Job = toml::from_str(
+        r#"
+expenses = 2
+drone = true
+
+[shoot_type.HalfDayBased]
+halves = 2
+image_prep = false
+assistant_hours = 0.0
+use_higher_prep_price = false
+use_higher_assistant_price = false
+photographer = "Colin"
+"#,
+    )
+    .unwrap();
+    assert_eq!(job.shoots, vec![half_day(2, Photographer::new("Colin"))]);
+    assert!(job.drone);
+    assert_eq!(job.expenses, 2);
+}
+
+#[test]
+fn jobs_round_trip_through_the_library_format() {
+    let job = Job { expenses: 3, ..Job::default() };
+    let loaded: Job = toml::from_str(&toml::to_string(&job).unwrap()).unwrap();
+    assert_eq!(loaded.shoots, job.shoots);
+    assert_eq!(loaded.expenses, 3);
+}
+
+#[test]
+fn quotes_saved_before_travel_legs_keep_their_travel() {
+    let job: Job = toml::from_str("travel_hours = 4\ntravel_people = 2\ntravel_days = 3\n").unwrap();
+    let travel = Travel {
+        legs: vec![Leg { hours: 4.0, ..Leg::default() }],
+        people: 2,
+        nights: 0,
+        meal_days: 3,
+    };
+    assert_eq!(job.travel, travel);
+    //short trips had no per diem
+    let job: Job = toml::from_str("travel_hours = 2\ntravel_people = 1\ntravel_days = 2\n").unwrap();
+    assert_eq!(job.travel.meal_days, 0);
+    assert_eq!(job.travel.hours(), 2.0);
+    //only the cliff is gone: two people three hours away were $750 and the first two hours now
+    //bill at the short trip rate, still once for the car
+    let job: Job = toml::from_str("travel_hours = 3\ntravel_people = 2\ntravel_days = 2\n").unwrap();
+    assert_eq!(job.travel.calc_price(&RateCard::default()).total(), Money::dollars(800));
+}
@@ -0,0 +1,60 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::solve::*;
+
+mod common;
+use common::*;
+
+fn solved(job: &Job, budget: i64) -> Option<ShootType> {
+    solve(job, 0, Money::dollars(budget), &RateCard::default()).map(|solution| solution.job.shoots[0].clone())
+}
+
+#[test]
+fn hourly_budget_is_solved_to_the_quarter_hour() {
+    //Ken is $275 an hour, so $2000 buys 7.25 hours ($1993.75)
+    let Some(ShootType::Hourly { hours, .. }) = solved(&job(ShootKind::Hourly.default_shoot(&RateCard::default())), 2000) else { panic!() };
+    assert_eq!(hours, 7.25);
+}
+
+#[test]
+fn conference_hours_fit_the_budget() {
+    let conference = ShootKind::Conference.default_shoot(&RateCard::default());
+    let Some(ShootType::Conference { hours, .. }) = solved(&job(conference), 1000) else { panic!() };
+    assert_eq!(hours, 5.0);
+}
+
+#[test]
+fn half_days_fit_the_budget() {
+    //1500 + 1000 + 1500 = 4000
+    let half_day = ShootKind::HalfDayBased.default_shoot(&RateCard::default());
+    let Some(ShootType::HalfDayBased { halves, .. }) = solved(&job(half_day), 4499) else { panic!() };
+    assert_eq!(halves, 3);
+}
+
+#[test]
+fn headshots_fill_the_last_started_hour() {
+    //team headshots are $200 an hour plus a $40 assistant, with an hour per started dozen plus setup:
+    //12 people cost $480 and 13 would cost $720
+    let Some(ShootType::Headshot { heads, .. }) = solved(&job(headshot(0, HeadshotType::new("Team"))), 700) else { panic!() };
+    assert_eq!(heads, 12);
+}
+
+#[test]
+fn other_shoots_and_extras_stay_fixed() {
+    let job = Job {
+        shoots: vec![ShootKind::Hourly.default_shoot(&RateCard::default()), ShootKind::Conference.default_shoot(&RateCard::default())],
+        drone: true,
+        ..Job::default()
+    };
+    let solution = solve(&job, 1, Money::dollars(1150), &RateCard::default()).unwrap();
+    assert_eq!(solution.job.shoots[0], job.shoots[0]);
+    assert_eq!(solution.job.shoots[1], ShootType::Conference { hours: 5.0, extra_cost: Money::ZERO });
+    assert_eq!(solution.quote.total(), Money::dollars(1150));
+}
+
+#[test]
+fn nothing_fits_when_the_rest_is_over_budget() {
+    let job = Job { drone: true, ..job(ShootKind::Hourly.default_shoot(&RateCard::default())) };
+    assert!(solved(&job, 100).is_none());
+}
@@ -0,0 +1,88 @@
+use shoot_pricing::calc::*;
+use shoot_pricing::discount::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::{ItemKind, Quote};
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::tax::*;
+use shoot_pricing::travel::*;
+
+mod common;
+use common::*;
+
+fn rates() -> RateCard {
+    let mut rates = RateCard::default();
+    rates.tax.jurisdictions.push(Jurisdiction {
+        name: "Ohio".to_string(),
+        percent: 8.0,
+        taxable: vec![ItemKind::Photography, ItemKind::Drone, ItemKind::Retouching],
+    });
+    rates
+}
+
+//Ken for 3 hours ($825, taxable), the drone ($150, taxable), 3 hours of travel ($200) and
+//two days of per diem ($300)
+fn job(discounts: Vec<Discount>) -> Job {
+    Job {
+        shoots: vec![hourly(3.0, Photographer::new("Ken"))],
+        drone: true,
+        travel: Travel {
+            legs: vec![Leg { hours: 3.0, ..Leg::default() }],
+            meal_days: 2,
+            ..Travel::default()
+        },
+        jurisdiction: "ohio".to_string(),
+        discounts,
+        ..Job::default()
+    }
+}
+
+fn tax_line(quote: &Quote) -> Option<(&str, Money)> {
+    quote
+        .items
+        .iter()
+        .find(|item| item.kind == ItemKind::Tax)
+        .map(|item| (item.label.as_str(), item.subtotal))
+}
+
+#[test]
+fn only_taxable_items_are_taxed() {
+    let quote = job(Vec::new()).calc_price(&rates());
+    assert_eq!(tax_line(&quote), Some(("Ohio tax (8%)", Money::dollars(78))));
+    assert_eq!(quote.total_before_tax(), Money::dollars(1475));
+    assert_eq!(quote.total(), Money::dollars(1475 + 78));
+    assert_eq!(quote.tax(), Money::dollars(78));
+}
+
+#[test]
+fn whole_quote_discounts_reduce_tax_in_proportion() {
+    //$147.50 off, of which 975/1475 came off taxable items
+    let quote = job(vec![Discount::percent("Repeat client", 10.0)]).calc_price(&rates());
+    assert_eq!(tax_line(&quote), Some(("Ohio tax (8%)", Money::from_dollars_f64(70.2))));
+}
+
+#[test]
+fn discounts_on_untaxed_items_leave_tax_alone() {
+    let free_per_diem = Discount {
+        name: "Free per diem".to_string(),
+        amount: DiscountAmount::Fixed(Money::dollars(300)),
+        items: vec![ItemKind::PerDiem],
+    };
+    let quote = job(vec![free_per_diem]).calc_price(&rates());
+    assert_eq!(quote.tax(), Money::dollars(78));
+}
+
+#[test]
+fn no_tax_without_a_known_jurisdiction() {
+    let none = Job { jurisdiction: String::new(), ..job(Vec::new()) };
+    assert_eq!(tax_line(&none.calc_price(&rates())), None);
+    let unknown = Job { jurisdiction: "Atlantis".to_string(), ..job(Vec::new()) };
+    assert_eq!(tax_line(&unknown.calc_price(&rates())), None);
+}
+
+#[test]
+fn tax_rates_over_100_percent_are_rejected() {
+    let mut rates = rates();
+    rates.tax.jurisdictions[0].percent = 108.0;
+    let err = rates.validate().unwrap_err();
+    assert_eq!(err.to_string(), "`tax.jurisdictions.Ohio` must be between 0 and 100% (108%)");
+}
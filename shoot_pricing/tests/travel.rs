@@ -0,0 +1,127 @@
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::Quote;
+use shoot_pricing::rates::RateCard;
+use shoot_pricing::travel::*;
+
+mod common;
+use common::lines;
+
+fn leg(hours: f32) -> Leg {
+    Leg { hours, ..Leg::default() }
+}
+
+fn trip(legs: Vec<Leg>) -> Travel {
+    Travel { legs, ..Travel::default() }
+}
+
+fn price(travel: &Travel) -> Quote {
+    travel.calc_price(&RateCard::default())
+}
+
+#[test]
+fn no_legs_no_travel() {
+    assert!(price(&Travel { people: 3, ..Travel::default() }).items.is_empty());
+}
+
+#[test]
+fn short_trips_are_charged_at_the_short_rate() {
+    assert_eq!(lines(&price(&trip(vec![leg(2.0)]))), vec![("Travel time", 2.0, Money::dollars(150))]);
+}
+
+#[test]
+fn time_past_the_short_trip_is_cheaper_without_a_cliff() {
+    assert_eq!(
+        lines(&price(&trip(vec![leg(2.5)]))),
+        vec![
+            ("Travel time", 2.0, Money::dollars(150)),
+            ("Travel time over 2 hours", 0.5, Money::dollars(25)),
+        ]
+    );
+    //one more quarter hour never makes the trip cheaper
+    let before = price(&trip(vec![leg(2.0)])).total();
+    let after = price(&trip(vec![leg(2.25)])).total();
+    assert!(after > before);
+}
+
+#[test]
+fn legs_add_up_for_the_short_trip_rule() {
+    let one = price(&trip(vec![leg(3.0)])).total();
+    let two = price(&trip(vec![leg(1.5), leg(1.5)])).total();
+    assert_eq!(one, two);
+}
+
+#[test]
+fn people_multiply_their_own_costs_but_not_the_car_or_the_drive() {
+    let travel = Travel {
+        legs: vec![Leg { name: "Columbus".to_string(), hours: 1.0, miles: 100.0, ..Leg::default() }],
+        people: 2,
+        ..Travel::default()
+    };
+    assert_eq!(
+        lines(&price(&travel)),
+        vec![
+            ("Travel time", 1.0, Money::dollars(75)),
+            ("Mileage (Columbus)", 100.0, Money::dollars(67)),
+        ]
+    );
+}
+
+#[test]
+fn travel_time_is_billed_once_however_many_people_go() {
+    let travel = Travel { legs: vec![leg(2.0)], people: 2, meal_days: 2, ..Travel::default() };
+    assert_eq!(
+        lines(&price(&travel)),
+        vec![
+            ("Travel time", 2.0, Money::dollars(150)),
+            ("Per diem for 2 people", 4.0, Money::dollars(600)),
+        ]
+    );
+}
+
+#[test]
+fn lodging_and_meals_are_per_person() {
+    let travel = Travel { legs: vec![leg(1.0)], people: 2, nights: 2, meal_days: 3 };
+    let quote = price(&travel);
+    assert!(lines(&quote).contains(&("Per diem for 2 people", 6.0, Money::dollars(900))));
+    assert!(lines(&quote).contains(&("Lodging for 2 people", 4.0, Money::dollars(500))));
+}
+
+#[test]
+fn flights_tolls_and_parking_are_passed_through() {
+    let travel = Travel {
+        legs: vec![Leg {
+            name: "NYC".to_string(),
+            flights: Money::dollars(320),
+            tolls: Money::from_dollars_f64(12.5),
+            parking: Money::dollars(40),
+            ..Leg::default()
+        }],
+        people: 3,
+        ..Travel::default()
+    };
+    assert_eq!(
+        lines(&price(&travel)),
+        vec![
+            ("Flights (NYC) for 3 people", 3.0, Money::dollars(960)),
+            ("Tolls (NYC)", 1.0, Money::from_dollars_f64(12.5)),
+            ("Parking (NYC)", 1.0, Money::dollars(40)),
+        ]
+    );
+}
+
+#[test]
+fn per_person_and_shared_costs_make_up_the_total() {
+    let rates = RateCard::default();
+    let travel = Travel {
+        legs: vec![
+            Leg { hours: 2.75, miles: 180.0, tolls: Money::dollars(8), ..Leg::default() },
+            Leg { hours: 1.0, flights: Money::dollars(210), parking: Money::dollars(25), ..Leg::default() },
+        ],
+        people: 3,
+        nights: 1,
+        meal_days: 2,
+    };
+    let per_person = travel.per_person(&rates).total();
+    let shared = travel.shared(&rates).total();
+    assert_eq!(travel.calc_price(&rates).total(), per_person.times(3.0) + shared);
+}
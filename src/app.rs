@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use shoot_pricing::calc::*;
+use shoot_pricing::compare::CompareInputs;
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::Quote;
+use shoot_pricing::rates::*;
+
+use crate::library::*;
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalcApp {
+    pub job: Job,
+    //inputs of the kinds each shoot was switched away from, kept for this session only
+    #[serde(skip)]
+    pub remembered: Vec<HashMap<ShootKind, ShootType>>,
+    pub details: QuoteDetails,
+    //rates always come from the rates file, never from the saved app state
+    #[serde(skip)]
+    pub rates: RateCard,
+    #[serde(skip)]
+    pub rate_source: RateSource,
+    pub show_rates: bool,
+    #[serde(skip)]
+    pub rates_error: Option<String>,
+    pub show_library: bool,
+    pub library_search: String,
+    pub show_compare: bool,
+    pub compare: CompareInputs,
+    pub tax_display: TaxDisplay,
+    //shows what the job costs and the margin, never on anything the client sees
+    pub internal_view: bool,
+    pub show_budget: bool,
+    pub budget: Money,
+    //which shoot the budget solver varies
+    pub budget_shoot: usize,
+    //None when the library file could not be read, so it is never overwritten
+    #[serde(skip)]
+    pub library: Option<QuoteLibrary>,
+    #[serde(skip)]
+    pub library_error: Option<String>,
+    #[serde(skip)]
+    pub export_message: Option<String>,
+}
+
+//whether the price label leads with the total before or after tax
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum TaxDisplay {
+    #[default]
+    Exclusive,
+    Inclusive,
+}
+
+impl TaxDisplay {
+    pub fn price(self, quote: &Quote) -> Money {
+        match self {
+            TaxDisplay::Exclusive => quote.total_before_tax(),
+            TaxDisplay::Inclusive => quote.total(),
+        }
+    }
+
+    pub fn label(self, quote: &Quote) -> String {
+        let tax = quote.tax();
+        if tax == Money::ZERO {
+            return quote.total().to_string();
+        }
+        match self {
+            TaxDisplay::Exclusive => format!("{} + {} tax", quote.total_before_tax(), tax),
+            TaxDisplay::Inclusive => format!("{} incl. {} tax", quote.total(), tax),
+        }
+    }
+}
+
+impl Default for CalcApp {
+    fn default() -> CalcApp {
+        CalcApp {
+            job: Job::default(),
+            remembered: Vec::new(),
+            details: QuoteDetails::default(),
+            rates: RateCard::default(),
+            rate_source: RateSource::BuiltIn,
+            show_rates: false,
+            rates_error: None,
+            show_library: false,
+            library_search: String::new(),
+            show_compare: false,
+            compare: CompareInputs::default(),
+            tax_display: TaxDisplay::Exclusive,
+            internal_view: false,
+            show_budget: false,
+            budget: Money::ZERO,
+            budget_shoot: 0,
+            library: None,
+            library_error: None,
+            export_message: None,
+        }
+    }
+}
+
+impl CalcApp {
+    //picks up the last session's quote if there is one
+    pub fn new(cc: &eframe::CreationContext<'_>) -> CalcApp {
+        let saved: Option<CalcApp> = cc.storage.and_then(|storage| eframe::get_value(storage, eframe::APP_KEY));
+        let (rates, rate_source) = load_rates();
+        let mut app = saved.unwrap_or_else(|| CalcApp {
+            job: Job::new(&rates),
+            compare: CompareInputs::new(&rates),
+            ..CalcApp::default()
+        });
+        (app.rates, app.rate_source) = (rates, rate_source);
+        match load_library() {
+            Ok(library) => app.library = Some(library),
+            Err(err) => app.library_error = Some(err.to_string()),
+        }
+        app
+    }
+
+    pub fn calc_price(&self) -> Quote {
+        self.job.calc_price(&self.rates)
+    }
+}
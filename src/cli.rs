@@ -0,0 +1,351 @@
+use core::fmt::Display;
+use std::str::FromStr;
+
+use clap::{Args, Parser, Subcommand};
+use shoot_pricing::calc::*;
+use shoot_pricing::discount::ClientRate;
+use shoot_pricing::money::Money;
+use shoot_pricing::quote::Quote;
+use shoot_pricing::rates::*;
+use shoot_pricing::travel::*;
+
+#[derive(Parser)]
+#[command(about = "Photo shoot price calculator, opens the calculator window when run without a command")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Print the price of a shoot
+    Price(PriceArgs),
+}
+
+#[derive(Args)]
+pub struct PriceArgs {
+    #[command(subcommand)]
+    shoot: ShootArgs,
+    #[arg(long, global = true)]
+    drone: bool,
+    #[arg(long, global = true, default_value_t = 0)]
+    expenses: u32,
+    #[command(flatten)]
+    travel: TravelArgs,
+    /// Standard, nonprofit or student rate
+    #[arg(long, global = true, default_value = "standard")]
+    client: ClientRate,
+    #[arg(long, global = true, default_value = "")]
+    promo_code: String,
+    /// Tax jurisdiction from the rates file, no tax when left out
+    #[arg(long, global = true, default_value = "")]
+    jurisdiction: String,
+    /// Print every line item instead of just the total
+    #[arg(long, global = true)]
+    breakdown: bool,
+}
+
+/// A single travel leg, the calculator window handles trips with several
+#[derive(Args)]
+struct TravelArgs {
+    #[arg(long, value_parser = non_negative::<f32>, global = true, default_value_t = 0.0)]
+    travel_hours: f32,
+    #[arg(long, value_parser = non_negative::<f32>, global = true, default_value_t = 0.0)]
+    miles: f32,
+    /// Flights per person in dollars
+    #[arg(long, value_parser = non_negative::<f64>, global = true, default_value_t = 0.0)]
+    flights: f64,
+    #[arg(long, value_parser = non_negative::<f64>, global = true, default_value_t = 0.0)]
+    tolls: f64,
+    #[arg(long, value_parser = non_negative::<f64>, global = true, default_value_t = 0.0)]
+    parking: f64,
+    #[arg(long, global = true, default_value_t = 1)]
+    travel_people: u32,
+    #[arg(long, global = true, default_value_t = 0)]
+    nights: u32,
+    /// Days of per diem for each traveller, older scripts pass it as --travel-days
+    #[arg(long, global = true, alias = "travel-days", default_value_t = 0)]
+    meal_days: u32,
+}
+
+//anything past this is a typo, and keeps prices well inside what Money holds
+const MAX_INPUT: f64 = 1_000_000.0;
+
+//hours, miles and dollars are never below zero, never inf or NaN
+fn non_negative<T>(text: &str) -> Result<T, String>
+where
+    T: FromStr + Into<f64> + Copy,
+    T::Err: Display,
+{
+    let value: T = text.parse().map_err(|err: T::Err| err.to_string())?;
+    if (0.0..=MAX_INPUT).contains(&value.into()) {
+        Ok(value)
+    } else {
+        Err(format!("must be between 0 and {}", MAX_INPUT))
+    }
+}
+
+impl TravelArgs {
+    fn into_travel(self) -> Travel {
+        let leg = Leg {
+            name: String::new(),
+            hours: self.travel_hours,
+            miles: self.miles,
+            flights: Money::from_dollars_f64(self.flights),
+            tolls: Money::from_dollars_f64(self.tolls),
+            parking: Money::from_dollars_f64(self.parking),
+        };
+        Travel {
+            legs: if leg == Leg::default() { Vec::new() } else { vec![leg] },
+            people: self.travel_people,
+            nights: self.nights,
+            meal_days: self.meal_days,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum ShootArgs {
+    /// Photographer by the hour
+    Hourly {
+        #[arg(long, value_parser = non_negative::<f32>)]
+        hours: f32,
+        /// Name from the roster in the rates file, the first active photographer when left out
+        #[arg(long)]
+        photographer: Option<String>,
+        #[command(flatten)]
+        extras: ShootExtras,
+    },
+    /// Photographer by the half day
+    HalfDay {
+        #[arg(long)]
+        halves: u32,
+        /// Name from the roster in the rates file, the first active photographer when left out
+        #[arg(long)]
+        photographer: Option<String>,
+        #[command(flatten)]
+        extras: ShootExtras,
+    },
+    /// Headshot session priced by number of people
+    Headshot {
+        #[arg(long)]
+        heads: u32,
+        /// Headshot package from the rates file
+        #[arg(long = "type", default_value = "Large")]
+        headshot_type: String,
+        #[arg(long, default_value = "discount")]
+        retouch: RetouchLevel,
+        #[arg(long)]
+        editing: bool,
+        #[arg(long, default_value_t = 0)]
+        extra_retouched_photos: u32,
+        #[arg(long, default_value_t = 1)]
+        days: u32,
+    },
+    /// Conference coverage by the hour
+    Conference {
+        #[arg(long, value_parser = non_negative::<f32>)]
+        hours: f32,
+        /// Retouching / extra costs in dollars
+        #[arg(long, value_parser = non_negative::<f64>, default_value_t = 0.0)]
+        extra_cost: f64,
+    },
+}
+
+#[derive(Args)]
+struct ShootExtras {
+    #[arg(long, value_parser = non_negative::<f32>, default_value_t = 0.0)]
+    assistant_hours: f32,
+    #[arg(long)]
+    image_prep: bool,
+    #[arg(long)]
+    higher_prep_price: bool,
+    #[arg(long)]
+    higher_assistant_price: bool,
+}
+
+impl ShootArgs {
+    fn into_shoot_type(self, rates: &RateCard) -> ShootType {
+        let photographer = |name: Option<String>| name.map_or_else(|| Photographer::first(rates), Photographer);
+        match self {
+            ShootArgs::Hourly { hours, photographer: photographer_name, extras } => ShootType::Hourly {
+                hours,
+                image_prep: extras.image_prep,
+                assistant_hours: extras.assistant_hours,
+                use_higher_prep_price: extras.higher_prep_price,
+                use_higher_assistant_price: extras.higher_assistant_price,
+                photographer: photographer(photographer_name),
+                crew: Vec::new(),
+            },
+            ShootArgs::HalfDay { halves, photographer: photographer_name, extras } => ShootType::HalfDayBased {
+                halves,
+                image_prep: extras.image_prep,
+                assistant_hours: extras.assistant_hours,
+                use_higher_prep_price: extras.higher_prep_price,
+                use_higher_assistant_price: extras.higher_assistant_price,
+                photographer: photographer(photographer_name),
+                crew: Vec::new(),
+            },
+            ShootArgs::Headshot { heads, headshot_type, retouch, editing, extra_retouched_photos, days } => {
+                ShootType::Headshot {
+                    heads,
+                    headshot_type: HeadshotType(headshot_type),
+                    retouch_level: retouch,
+                    editing,
+                    extra_retouched_photos,
+                    days,
+                }
+            }
+            ShootArgs::Conference { hours, extra_cost } => ShootType::Conference {
+                hours,
+                extra_cost: Money::from_dollars_f64(extra_cost),
+            },
+        }
+    }
+}
+
+impl PriceArgs {
+    fn into_job(self, rates: &RateCard) -> (Job, bool) {
+        let job = Job {
+            shoots: vec![self.shoot.into_shoot_type(rates)],
+            expenses: self.expenses,
+            drone: self.drone,
+            travel: self.travel.into_travel(),
+            client_rate: self.client,
+            promo_code: self.promo_code,
+            jurisdiction: self.jurisdiction,
+            ..Job::default()
+        };
+        (job, self.breakdown)
+    }
+}
+
+//names the rates file does not know would price at nothing, so they stop the command
+fn check_names(job: &Job, rates: &RateCard) -> Result<(), String> {
+    let mut problems = Vec::new();
+    for photographer in job.shoots.iter().flat_map(ShootType::photographers) {
+        if photographer.rates(rates).is_none() {
+            problems.push(format!("`{}` is not on the photographer roster", photographer));
+        }
+    }
+    for shoot in &job.shoots {
+        if let ShootType::Headshot { headshot_type, .. } = shoot {
+            if headshot_type.package(rates).is_none() {
+                problems.push(format!("unknown headshot package `{}`", headshot_type));
+            }
+        }
+    }
+    if !job.promo_code.is_empty() && rates.discounts.promo(&job.promo_code).is_none() {
+        problems.push(format!("unknown promo code `{}`", job.promo_code));
+    }
+    if !job.jurisdiction.is_empty() && rates.tax.jurisdiction(&job.jurisdiction).is_none() {
+        problems.push(format!("unknown tax jurisdiction `{}`", job.jurisdiction));
+    }
+    match problems.is_empty() {
+        true => Ok(()),
+        false => Err(problems.join("\n")),
+    }
+}
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Price(args) => {
+            let (rates, source) = load_rates();
+            if let RateSource::Invalid(..) = source {
+                eprintln!("warning: using {}", source);
+            }
+            let (job, breakdown) = args.into_job(&rates);
+            check_names(&job, &rates)?;
+            let quote = job.calc_price(&rates);
+            if quote.out_of_range() {
+                return Err("the price is too large to work out, check the hours and amounts".to_string());
+            }
+            if breakdown {
+                print_breakdown(&quote);
+            } else {
+                println!("{}", quote.total().to_plain_string());
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_breakdown(quote: &Quote) {
+    for item in &quote.items {
+        println!(
+            "{:<40} {:>6} {:>10} {:>10}",
+            item.label,
+            item.quantity,
+            item.unit_price.to_string(),
+            item.subtotal.to_string()
+        );
+    }
+    println!("{:<40} {:>6} {:>10} {:>10}", "Total", "", "", quote.total().to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::error::ErrorKind;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("shoot_calc").chain(args.iter().copied()))
+    }
+
+    fn price(args: &[&str]) -> PriceArgs {
+        match parse(args).unwrap().command {
+            Some(Command::Price(args)) => args,
+            None => panic!("no command"),
+        }
+    }
+
+    #[test]
+    fn documented_example() {
+        let args = price(&[
+            "price", "hourly", "--hours", "3", "--photographer", "colin", "--drone", "--travel-hours", "4",
+            "--travel-days", "2",
+        ]);
+        assert!(args.drone);
+        assert_eq!(args.travel.travel_hours, 4.0);
+        assert_eq!(args.travel.meal_days, 2);
+        let (job, _) = args.into_job(&RateCard::default());
+        assert!(check_names(&job, &RateCard::default()).is_ok());
+        assert_eq!(job.calc_price(&RateCard::default()).total(), Money::dollars(1375));
+    }
+
+    #[test]
+    fn no_command_opens_the_window() {
+        assert!(parse(&[]).unwrap().command.is_none());
+    }
+
+    #[test]
+    fn negative_huge_and_non_finite_numbers_are_rejected() {
+        for hours in ["-1", "inf", "NaN", "1e30"] {
+            let hours = format!("--hours={}", hours);
+            let err = parse(&["price", "conference", &hours]).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::ValueValidation, "{}", hours);
+        }
+        let err = parse(&["price", "hourly", "--hours", "3", "--flights=-20"]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn unknown_flags_and_choices_are_rejected() {
+        assert_eq!(parse(&["price", "hourly", "--hourz", "3"]).err().unwrap().kind(), ErrorKind::UnknownArgument);
+        let err = parse(&["price", "hourly", "--hours", "3", "--client", "charity"]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn names_missing_from_the_rates_file_stop_the_command() {
+        let rates = RateCard::default();
+        let args = price(&[
+            "price", "headshot", "--heads", "5", "--type", "larg", "--promo-code", "nope", "--jurisdiction", "mars",
+        ]);
+        let (job, _) = args.into_job(&rates);
+        assert_eq!(check_names(&job, &rates).err().unwrap().lines().count(), 3);
+        let (job, _) = price(&["price", "hourly", "--hours", "3", "--photographer", "kenn"]).into_job(&rates);
+        assert!(check_names(&job, &rates).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use core::fmt::Display;
+use std::path::PathBuf;
+
+use printpdf::*;
+use shoot_pricing::calc::Job;
+use shoot_pricing::payment::{Payment, PaymentError};
+use shoot_pricing::quote::Quote;
+use shoot_pricing::rates::RateCard;
+
+use crate::library::QuoteDetails;
+
+const BRAND: &str = "HuthPhoto";
+const TERMS: &str = "This estimate is valid for 30 days from the date above. \
+Final pricing may change if the number of hours, people or days changes. \
+Travel, per diem and expenses are billed as listed. \
+Images are delivered online after payment in full.";
+
+//US letter
+const PAGE_WIDTH: f32 = 215.9;
+const PAGE_HEIGHT: f32 = 279.4;
+const MARGIN: f32 = 20.0;
+const WRAP_CHARS: usize = 90;
+
+//columns of the breakdown table, in mm from the left edge
+const QTY_X: f32 = 120.0;
+const UNIT_X: f32 = 140.0;
+const SUBTOTAL_X: f32 = 170.0;
+//due date column of the payment schedule
+const DUE_X: f32 = 80.0;
+
+struct Writer {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    y: f32,
+}
+
+impl Writer {
+    fn new(title: &str) -> Result<Writer, Error> {
+        let (doc, page, layer) = PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "estimate");
+        let layer = doc.get_page(page).get_layer(layer);
+        let regular = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+        let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+        Ok(Writer { doc, layer, regular, bold, y: PAGE_HEIGHT - MARGIN })
+    }
+
+    //moves down by one line of the given size, starting a new page when needed
+    fn advance(&mut self, size: f32) {
+        let height = size * 0.5;
+        if self.y - height < MARGIN {
+            let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "estimate");
+            self.layer = self.doc.get_page(page).get_layer(layer);
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+        self.y -= height;
+    }
+
+    fn columns(&mut self, columns: &[(f32, &str)], size: f32, bold: bool) {
+        self.advance(size);
+        let font = if bold { &self.bold } else { &self.regular };
+        for (x, text) in columns {
+            self.layer.use_text(*text, size, Mm(*x), Mm(self.y), font);
+        }
+    }
+
+    fn line(&mut self, text: &str, size: f32, bold: bool) {
+        self.columns(&[(MARGIN, text)], size, bold);
+    }
+
+    fn paragraph(&mut self, text: &str, size: f32) {
+        for line in text.lines() {
+            for wrapped in wrap(line, WRAP_CHARS) {
+                self.line(&wrapped, size, false);
+            }
+        }
+    }
+
+    fn gap(&mut self) {
+        self.y -= 4.0;
+    }
+}
+
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![String::new()];
+    for word in text.split_whitespace() {
+        let current = lines.last_mut().unwrap();
+        if !current.is_empty() && current.len() + word.len() + 1 > width {
+            lines.push(word.to_string());
+        } else {
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+    }
+    lines
+}
+
+pub fn estimate_pdf(
+    details: &QuoteDetails,
+    job: &Job,
+    quote: &Quote,
+    payments: &[Payment],
+    rates: &RateCard,
+) -> Result<Vec<u8>, Error> {
+    let mut w = Writer::new(&format!("{} estimate {}", BRAND, details.name))?;
+
+    w.line(BRAND, 24.0, true);
+    w.line("Estimate", 16.0, false);
+    w.gap();
+
+    w.line(&format!("Client: {}", details.client), 11.0, false);
+    w.line(&format!("Quote: {}", details.name), 11.0, false);
+    w.line(&format!("Date: {}", details.date), 11.0, false);
+    w.line(&format!("Shoot date: {}", details.shoot_date), 11.0, false);
+    if !details.notes.is_empty() {
+        w.gap();
+        w.paragraph(&details.notes, 10.0);
+    }
+
+    w.gap();
+    w.line("Shoot", 13.0, true);
+    for (index, shoot) in job.shoots.iter().enumerate() {
+        w.line(&format!("{}. {}", index + 1, shoot.description()), 11.0, false);
+        if let Some(features) = shoot.features(rates) {
+            w.paragraph(&features, 10.0);
+        }
+    }
+
+    w.gap();
+    w.line("Breakdown", 13.0, true);
+    w.columns(
+        &[(MARGIN, "Item"), (QTY_X, "Qty"), (UNIT_X, "Unit"), (SUBTOTAL_X, "Subtotal")],
+        10.0,
+        true,
+    );
+    for item in &quote.items {
+        w.columns(
+            &[
+                (MARGIN, &item.label),
+                (QTY_X, &item.quantity.to_string()),
+                (UNIT_X, &item.unit_price.to_string()),
+                (SUBTOTAL_X, &item.subtotal.to_string()),
+            ],
+            10.0,
+            false,
+        );
+    }
+    w.gap();
+    w.columns(&[(UNIT_X, "Total"), (SUBTOTAL_X, &quote.total().to_string())], 12.0, true);
+
+    w.gap();
+    w.line("Payment schedule", 13.0, true);
+    for payment in payments {
+        w.columns(
+            &[
+                (MARGIN, &payment.name),
+                (DUE_X, &details.due_text(payment.due)),
+                (SUBTOTAL_X, &payment.amount.to_string()),
+            ],
+            10.0,
+            false,
+        );
+    }
+
+    w.gap();
+    w.line("Terms", 13.0, true);
+    w.paragraph(TERMS, 9.0);
+
+    w.doc.save_to_bytes()
+}
+
+#[derive(Debug)]
+pub enum EstimateError {
+    Pdf(Error),
+    Payment(PaymentError),
+    Io(std::io::Error),
+    NoDocumentDir,
+}
+
+impl Display for EstimateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EstimateError::Pdf(err) => write!(f, "could not create estimate: {}", err),
+            EstimateError::Payment(err) => write!(f, "could not create estimate: {}", err),
+            EstimateError::Io(err) => write!(f, "could not save estimate: {}", err),
+            EstimateError::NoDocumentDir => write!(f, "no documents folder to save the estimate to"),
+        }
+    }
+}
+
+impl std::error::Error for EstimateError {}
+
+pub fn estimate_path(details: &QuoteDetails) -> Option<PathBuf> {
+    let name = if details.client.is_empty() { &details.name } else { &details.client };
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let file = format!("estimate_{}_{}.pdf", name, details.date);
+    dirs::document_dir().or_else(dirs::home_dir).map(|dir| dir.join(file))
+}
+
+pub fn export_estimate(
+    details: &QuoteDetails,
+    job: &Job,
+    quote: &Quote,
+    rates: &RateCard,
+) -> Result<PathBuf, EstimateError> {
+    let path = estimate_path(details).ok_or(EstimateError::NoDocumentDir)?;
+    let payments = job.schedule(rates, quote).map_err(EstimateError::Payment)?;
+    let bytes = estimate_pdf(details, job, quote, &payments, rates).map_err(EstimateError::Pdf)?;
+    std::fs::write(&path, bytes).map_err(EstimateError::Io)?;
+    Ok(path)
+}
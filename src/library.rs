@@ -0,0 +1,171 @@
+use core::fmt::Display;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use shoot_pricing::calc::{Job, ShootType};
+use shoot_pricing::payment::Due;
+use shoot_pricing::rates::RateCard;
+
+const LIBRARY_FILE: &str = "quotes.toml";
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuoteDetails {
+    pub name: String,
+    pub client: String,
+    pub date: NaiveDate,
+    //payment due dates count from here
+    pub shoot_date: NaiveDate,
+    pub notes: String,
+}
+
+impl Default for QuoteDetails {
+    fn default() -> QuoteDetails {
+        QuoteDetails {
+            name: String::new(),
+            client: String::new(),
+            date: chrono::Local::now().date_naive(),
+            shoot_date: chrono::Local::now().date_naive(),
+            notes: String::new(),
+        }
+    }
+}
+
+impl QuoteDetails {
+    pub fn due_date(&self, due: Due) -> Option<NaiveDate> {
+        match due {
+            Due::Booking => None,
+            Due::ShootDay(days) => self.shoot_date.checked_add_signed(chrono::Duration::days(days.into())),
+        }
+    }
+
+    pub fn due_text(&self, due: Due) -> String {
+        match self.due_date(due) {
+            Some(date) => format!("{} ({})", date, due),
+            None => due.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedQuote {
+    pub details: QuoteDetails,
+    pub job: Job,
+}
+
+impl SavedQuote {
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+        [&self.details.name, &self.details.client, &self.details.notes]
+            .iter()
+            .any(|text| text.to_lowercase().contains(&search))
+            || self.details.date.to_string().contains(&search)
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct QuoteLibrary {
+    pub quotes: Vec<SavedQuote>,
+}
+
+impl QuoteLibrary {
+    pub fn load(path: &Path) -> Result<QuoteLibrary, LibraryError> {
+        let text = std::fs::read_to_string(path).map_err(LibraryError::Io)?;
+        toml::from_str(&text).map_err(LibraryError::Parse)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), LibraryError> {
+        let text = toml::to_string_pretty(self).map_err(LibraryError::Serialize)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(LibraryError::Io)?;
+        }
+        std::fs::write(path, text).map_err(LibraryError::Io)
+    }
+
+    //a quote saved under an existing name replaces the old one
+    pub fn insert(&mut self, quote: SavedQuote) {
+        match self.quotes.iter_mut().find(|saved| saved.details.name == quote.details.name) {
+            Some(saved) => *saved = quote,
+            None => self.quotes.push(quote),
+        }
+    }
+
+    pub fn duplicate(&mut self, index: usize) {
+        let mut copy = self.quotes[index].clone();
+        let mut n = 2;
+        while self.quotes.iter().any(|saved| saved.details.name == format!("{} ({})", copy.details.name, n)) {
+            n += 1;
+        }
+        copy.details.name = format!("{} ({})", copy.details.name, n);
+        self.quotes.insert(index + 1, copy);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        self.quotes.remove(index);
+    }
+
+    //photographers saved quotes book who are not on the roster, e.g. after a rename, with how
+    //many quotes name them
+    pub fn off_roster(&self, rates: &RateCard) -> BTreeMap<String, usize> {
+        let mut names = BTreeMap::new();
+        for quote in &self.quotes {
+            let mut missing: Vec<String> = quote
+                .job
+                .shoots
+                .iter()
+                .flat_map(ShootType::photographers)
+                .filter(|photographer| photographer.rates(rates).is_none())
+                .map(|photographer| photographer.to_string())
+                .collect();
+            missing.sort();
+            missing.dedup();
+            for name in missing {
+                *names.entry(name).or_insert(0) += 1;
+            }
+        }
+        names
+    }
+
+    pub fn search<'a>(&'a self, search: &'a str) -> impl Iterator<Item = (usize, &'a SavedQuote)> + 'a {
+        self.quotes.iter().enumerate().filter(move |(_, quote)| quote.matches(search))
+    }
+}
+
+#[derive(Debug)]
+pub enum LibraryError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    NoDataDir,
+}
+
+impl Display for LibraryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LibraryError::Io(err) => write!(f, "could not access quote library: {}", err),
+            LibraryError::Parse(err) => write!(f, "invalid quote library: {}", err),
+            LibraryError::Serialize(err) => write!(f, "could not write quote library: {}", err),
+            LibraryError::NoDataDir => write!(f, "no data directory for the quote library"),
+        }
+    }
+}
+
+impl std::error::Error for LibraryError {}
+
+pub fn library_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("shoot_calc").join(LIBRARY_FILE))
+}
+
+pub fn load_library() -> Result<QuoteLibrary, LibraryError> {
+    match library_path() {
+        Some(path) if path.exists() => QuoteLibrary::load(&path),
+        _ => Ok(QuoteLibrary::default()),
+    }
+}
+
+pub fn save_library(library: &QuoteLibrary) -> Result<(), LibraryError> {
+    let path = library_path().ok_or(LibraryError::NoDataDir)?;
+    library.save(&path)
+}
@@ -1,12 +1,43 @@
 use egui::*;
+use std::collections::HashMap;
 
-mod calc;
+mod app;
+mod cli;
+mod estimate;
+mod library;
 
-use calc::*;
+use app::*;
+use chrono::NaiveDate;
+use estimate::*;
+use library::*;
+use shoot_pricing::calc::*;
+use shoot_pricing::compare::*;
+use shoot_pricing::cost::*;
+use shoot_pricing::crew::*;
+use shoot_pricing::discount::*;
+use shoot_pricing::headshot::*;
+use shoot_pricing::money::Money;
+use shoot_pricing::payment::*;
+use shoot_pricing::quote::*;
+use shoot_pricing::rates::*;
+use shoot_pricing::roster::*;
+use shoot_pricing::solve::*;
+use shoot_pricing::travel::*;
 
 const MAX_RANGE: u32 = 1000;
 
 fn main() {
+    let cli = <cli::Cli as clap::Parser>::parse();
+    if let Some(command) = cli.command {
+        if let Err(err) = cli::run(command) {
+            for line in err.lines() {
+                eprintln!("error: {}", line);
+            }
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let native_options = eframe::NativeOptions::default();
     eframe::run_native(
         "calc",
@@ -17,140 +48,1039 @@ fn main() {
 }
 
 impl eframe::App for CalcApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, self);
+    }
+
     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        if self.show_rates {
+            egui::SidePanel::right("rates").show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        if ui.button("save").clicked() {
+                            match save_rates(&self.rates) {
+                                Ok(path) => {
+                                    self.rate_source = RateSource::File(path);
+                                    self.rates_error = None;
+                                }
+                                Err(err) => self.rates_error = Some(err.to_string()),
+                            }
+                        }
+                        if ui.button("reload").clicked() {
+                            (self.rates, self.rate_source) = load_rates();
+                            self.rates_error = None;
+                        }
+                        if ui.button("close").clicked() {
+                            self.show_rates = false;
+                        }
+                    });
+                    if let Some(err) = &self.rates_error {
+                        ui.colored_label(ui.visuals().error_fg_color, err);
+                    }
+                    let remembered = self.remembered.iter().flat_map(HashMap::values);
+                    let saved = self.library.iter().flat_map(|library| &library.quotes).flat_map(|quote| &quote.job.shoots);
+                    let in_use: Vec<String> = self
+                        .job
+                        .shoots
+                        .iter()
+                        .chain(remembered)
+                        .chain(saved)
+                        .flat_map(ShootType::photographers)
+                        .map(|photographer| photographer.to_string())
+                        .collect();
+                    ui_rates(ui, &mut self.rates, self.library.as_ref(), &in_use);
+                });
+            });
+        }
+
+        if self.show_library {
+            egui::SidePanel::left("library").show(ctx, |ui| self.ui_library(ui));
+        }
+
+        if self.show_compare {
+            let mut open = true;
+            egui::Window::new("Compare shoot types")
+                .open(&mut open)
+                .show(ctx, |ui| self.ui_compare(ui));
+            self.show_compare = open;
+        }
+
+        if self.show_budget {
+            let mut open = true;
+            egui::Window::new("Fit a budget")
+                .open(&mut open)
+                .show(ctx, |ui| self.ui_budget(ui));
+            self.show_budget = open;
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ctx.set_pixels_per_point(frame.info().native_pixels_per_point.unwrap_or(1.0) * 2.0);
 
-            egui::ComboBox::from_label("Shoot Type")
-                .selected_text(self.shoot_type.to_string())
-                .show_ui(ui, |ui| {
-                    ui.selectable_value(
-                        &mut self.shoot_type,
-                        ShootType::Hourly {
-                            hours: 0.0,
-                            image_prep: false,
-                            assistant_hours: 0.0,
-                            use_higher_prep_price: false,
-                            use_higher_assistant_price: false,
-                            photographer: Photographer::Ken,
-                        },
-                        "Hourly",
-                    );
-                    ui.selectable_value(
-                        &mut self.shoot_type,
-                        ShootType::HalfDayBased {
-                            halves: 0,
-                            image_prep: false,
-                            assistant_hours: 0.0,
-                            use_higher_prep_price: false,
-                            use_higher_assistant_price: false,
-                            photographer: Photographer::Ken,
-                        },
-                        "Half Day",
-                    );
-                    ui.selectable_value(
-                        &mut self.shoot_type,
-                        ShootType::Headshot {
-                            heads: 0,
-                            headshot_type: HeadshotType::Large,
-                            editing: false,
-                            retouch_level: RetouchLevel::Discount,
-                            extra_retouched_photos: 0,
-                            days: 1,
-                        },
-                        "Headshot",
-                    );
-                    ui.selectable_value(
-                        &mut self.shoot_type,
-                        ShootType::Conference { hours: 0.0 , extra_cost: 0.0},
-                        "Conference",
-                    );
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let mut action = None;
+                let count = self.job.shoots.len();
+                self.remembered.resize_with(count, HashMap::new);
+                let shoots = self.job.shoots.iter_mut().zip(self.remembered.iter_mut());
+                for (index, (shoot_type, remembered)) in shoots.enumerate() {
+                    ui.push_id(index, |ui| {
+                        ui.group(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.strong(format!("{}.", index + 1));
+                                if ui.add_enabled(index > 0, Button::new("up")).clicked() {
+                                    action = Some(ShootAction::Up(index));
+                                }
+                                if ui.add_enabled(index + 1 < count, Button::new("down")).clicked() {
+                                    action = Some(ShootAction::Down(index));
+                                }
+                                if ui.button("remove").clicked() {
+                                    action = Some(ShootAction::Remove(index));
+                                }
+                            });
+                            ui_shoot(ui, &self.rates, shoot_type, remembered);
+                        });
+                    });
+                }
+                match action {
+                    Some(ShootAction::Up(index)) => {
+                        self.job.shoots.swap(index, index - 1);
+                        self.remembered.swap(index, index - 1);
+                    }
+                    Some(ShootAction::Down(index)) => {
+                        self.job.shoots.swap(index, index + 1);
+                        self.remembered.swap(index, index + 1);
+                    }
+                    Some(ShootAction::Remove(index)) => {
+                        self.job.shoots.remove(index);
+                        self.remembered.remove(index);
+                    }
+                    None => {}
+                }
+                if ui.button("add shoot").clicked() {
+                    self.job.shoots.push(ShootKind::Hourly.default_shoot(&self.rates));
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut self.job.expenses));
+                    ui.label(format!("expenses ({} per)", self.rates.expenses));
+                });
+
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.job.drone, format!("drone ({})", self.rates.drone));
+                    //only checked once someone on the roster is marked as certified
+                    let certified = |photographer: &Photographer| {
+                        photographer.rates(&self.rates).is_some_and(|entry| entry.has_skill(DRONE_SKILL))
+                    };
+                    let roster_tracks_it = self.rates.photographers.active().any(|entry| entry.has_skill(DRONE_SKILL));
+                    if self.job.drone
+                        && roster_tracks_it
+                        && !self.job.shoots.iter().flat_map(ShootType::photographers).any(certified)
+                    {
+                        ui.colored_label(ui.visuals().warn_fg_color, "nobody on this job is drone certified");
+                    }
+                });
+
+                ui.separator();
+                ui_travel(ui, &self.rates, &mut self.job.travel);
+
+                ui.separator();
+                ui_discounts(ui, &self.rates, &mut self.job);
+                ui_tax(ui, &self.rates, &mut self.job);
+
+                ui.separator();
+                let quote = self.calc_price();
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(Label::new(self.tax_display.label(&quote)).sense(Sense::click()))
+                        .on_hover_text("click to copy")
+                        .clicked()
+                    {
+                        ui.output_mut(|o| o.copied_text = self.tax_display.price(&quote).to_plain_string());
+                    };
+                    if quote.tax() != Money::ZERO {
+                        ui.selectable_value(&mut self.tax_display, TaxDisplay::Exclusive, "excl. tax");
+                        ui.selectable_value(&mut self.tax_display, TaxDisplay::Inclusive, "incl. tax");
+                    }
+                });
+                if quote.out_of_range() {
+                    ui.colored_label(ui.visuals().error_fg_color, "too large to price, check the hours and rates");
+                }
+                ui_breakdown(ui, &quote);
+                ui.checkbox(&mut self.internal_view, "internal view");
+                if self.internal_view {
+                    ui_margin(ui, &self.rates, &self.job, &quote);
+                }
+
+                ui.separator();
+                ui_payments(ui, &self.rates, &mut self.job, &mut self.details, &quote);
+
+                if ui.button("export PDF estimate").clicked() {
+                    self.export_message = Some(match export_estimate(&self.details, &self.job, &quote, &self.rates) {
+                        Ok(path) => format!("saved {}", path.display()),
+                        Err(err) => err.to_string(),
+                    });
+                }
+                if let Some(message) = &self.export_message {
+                    ui.label(message);
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    match &self.rate_source {
+                        RateSource::Invalid(..) => ui.colored_label(ui.visuals().error_fg_color, self.rate_source.to_string()),
+                        _ => ui.weak(self.rate_source.to_string()),
+                    };
+                    if ui.button("edit rates").clicked() {
+                        self.show_rates = true;
+                    }
+                    if ui.button("quotes").clicked() {
+                        self.show_library = true;
+                    }
+                    if ui.button("compare").clicked() {
+                        self.show_compare = true;
+                    }
+                    if ui.button("budget").clicked() {
+                        self.show_budget = true;
+                    }
                 });
-            ui.horizontal(|ui| {
-                ui.add(DragValue::new(&mut self.expenses));
-                ui.label("expenses (10$ per)");
             });
+        });
+    }
+}
+
+impl CalcApp {
+    fn ui_library(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.heading("Quotes");
+            if ui.button("close").clicked() {
+                self.show_library = false;
+            }
+        });
+        if let Some(err) = &self.library_error {
+            ui.colored_label(ui.visuals().error_fg_color, err);
+        }
+        let Some(library) = &mut self.library else {
+            return;
+        };
+
+        egui::Grid::new("details").num_columns(2).show(ui, |ui| {
+            ui.label("name");
+            ui.text_edit_singleline(&mut self.details.name);
+            ui.end_row();
+            ui.label("client");
+            ui.text_edit_singleline(&mut self.details.client);
+            ui.end_row();
+            ui.label("date");
+            date_edit(ui, "quote date", &mut self.details.date);
+            ui.end_row();
+            ui.label("notes");
+            ui.text_edit_multiline(&mut self.details.notes);
+            ui.end_row();
+        });
+
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            if ui.add_enabled(!self.details.name.is_empty(), Button::new("save quote")).clicked() {
+                library.insert(SavedQuote { details: self.details.clone(), job: self.job.clone() });
+                changed = true;
+            }
+            if ui.button("new quote").clicked() {
+                self.details = QuoteDetails::default();
+                self.job = Job::new(&self.rates);
+                self.remembered.clear();
+            }
+        });
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label("search");
+            ui.text_edit_singleline(&mut self.library_search);
+        });
+
+        enum Action {
+            Open(usize),
+            Duplicate(usize),
+            Delete(usize),
+        }
+        let mut action = None;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (index, quote) in library.search(&self.library_search) {
+                ui.group(|ui| {
+                    ui.strong(&quote.details.name);
+                    ui.label(format!(
+                        "{} {} {}",
+                        quote.details.client,
+                        quote.details.date,
+                        quote.job.calc_price(&self.rates).total()
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.small_button("open").clicked() {
+                            action = Some(Action::Open(index));
+                        }
+                        if ui.small_button("duplicate").clicked() {
+                            action = Some(Action::Duplicate(index));
+                        }
+                        if ui.small_button("delete").clicked() {
+                            action = Some(Action::Delete(index));
+                        }
+                    });
+                });
+            }
+        });
+
+        match action {
+            Some(Action::Open(index)) => {
+                self.details = library.quotes[index].details.clone();
+                self.job = library.quotes[index].job.clone();
+                self.remembered.clear();
+            }
+            Some(Action::Duplicate(index)) => {
+                library.duplicate(index);
+                changed = true;
+            }
+            Some(Action::Delete(index)) => {
+                library.remove(index);
+                changed = true;
+            }
+            None => {}
+        }
+
+        if changed {
+            self.library_error = save_library(library).err().map(|err| err.to_string());
+        }
+    }
 
-            ui.checkbox(&mut self.drone, "drone ($150)");
+    //the job's drone, expenses and travel apply to every compared type
+    fn ui_compare(&mut self, ui: &mut Ui) {
+        let inputs = &mut self.compare;
+        ui_hourly(
+            ui,
+            &self.rates,
+            &mut inputs.hours,
+            &mut inputs.image_prep,
+            &mut inputs.assistant_hours,
+            &mut inputs.photographer,
+            &mut inputs.use_higher_prep_price,
+            &mut inputs.use_higher_assistant_price,
+        );
+        ui.separator();
 
+        let comparison = compare(&self.job, &self.compare, &self.rates);
+        let cheapest = comparison.cheapest();
+        egui::Grid::new("comparison").striped(true).show(ui, |ui| {
+            for (kind, quote) in &comparison.options {
+                let text = match kind {
+                    ShootKind::HalfDayBased => format!("{} ({})", kind, self.compare.halves()),
+                    _ => kind.to_string(),
+                };
+                if Some(*kind) == cheapest {
+                    ui.colored_label(Color32::GREEN, text);
+                    ui.colored_label(Color32::GREEN, quote.total().to_string());
+                } else {
+                    ui.label(text);
+                    ui.label(quote.total().to_string());
+                }
+                ui.end_row();
+            }
+        });
+        match comparison.break_even {
+            Some(BreakEven::At(hours)) => {
+                ui.label(format!("hourly costs the same as {} half days at {:.1} hours", self.compare.halves(), hours));
+            }
+            Some(BreakEven::HourlyCheaper(hours)) => {
+                ui.label(format!("hourly is cheaper than {} half days up to {} hours", self.compare.halves(), hours));
+            }
+            None => {}
+        }
+    }
+
+    fn ui_budget(&mut self, ui: &mut Ui) {
+        if self.job.shoots.is_empty() {
+            ui.label("add a shoot to fit it to a budget");
+            return;
+        }
+        self.budget_shoot = self.budget_shoot.min(self.job.shoots.len() - 1);
+        ui.horizontal(|ui| {
+            money_drag(ui, &mut self.budget, 10.0);
+            ui.label("budget");
+        });
+        egui::ComboBox::from_label("shoot to fit")
+            .selected_text(format!("{}. {}", self.budget_shoot + 1, self.job.shoots[self.budget_shoot]))
+            .show_ui(ui, |ui| {
+                for (index, shoot) in self.job.shoots.iter().enumerate() {
+                    ui.selectable_value(&mut self.budget_shoot, index, format!("{}. {}", index + 1, shoot));
+                }
+            });
+        ui.separator();
+
+        let Some(solution) = solve(&self.job, self.budget_shoot, self.budget, &self.rates) else {
+            ui.colored_label(ui.visuals().error_fg_color, "the rest of the quote is already over budget");
+            return;
+        };
+        ui.label(solution.job.shoots[self.budget_shoot].description());
+        ui_breakdown(ui, &solution.quote);
+        ui.strong(format!("Total {}", solution.quote.total()));
+        if ui.button("use this quote").clicked() {
+            self.job = solution.job;
+        }
+    }
+}
+
+enum ShootAction {
+    Up(usize),
+    Down(usize),
+    Remove(usize),
+}
+
+fn ui_shoot(ui: &mut Ui, rates: &RateCard, shoot_type: &mut ShootType, remembered: &mut HashMap<ShootKind, ShootType>) {
+    egui::ComboBox::from_label("Shoot Type")
+        .selected_text(shoot_type.to_string())
+        .show_ui(ui, |ui| {
+            for kind in ShootKind::ALL {
+                let selected = shoot_type.kind() == kind;
+                if ui.selectable_label(selected, kind.to_string()).clicked() && !selected {
+                    shoot_type.switch_kind(kind, remembered, rates);
+                }
+            }
+        });
+
+    match shoot_type {
+        ShootType::Hourly {
+            hours,
+            image_prep,
+            assistant_hours,
+            photographer,
+            use_higher_assistant_price,
+            use_higher_prep_price,
+            crew,
+        } => {
+            ui_hourly(ui, rates, hours, image_prep, assistant_hours, photographer, use_higher_prep_price, use_higher_assistant_price);
+            ui_crew(ui, rates, crew, false);
+        }
+
+        ShootType::HalfDayBased {
+            halves,
+            image_prep,
+            assistant_hours,
+            photographer,
+            use_higher_assistant_price,
+            use_higher_prep_price,
+            crew,
+        } => {
+            ui_half_day_based(ui, rates, halves, image_prep, assistant_hours, photographer, use_higher_prep_price, use_higher_assistant_price);
+            ui_crew(ui, rates, crew, true);
+        }
+
+        ShootType::Headshot {
+            heads,
+            headshot_type,
+            editing,
+            retouch_level,
+            extra_retouched_photos,
+            days,
+        } => {
+            ui_headshot(ui, rates, heads, headshot_type, editing, retouch_level, extra_retouched_photos, days);
+        }
+        ShootType::Conference { hours , extra_cost} => {
+            ui_conference(ui, hours, extra_cost)
+        }
+    }
+}
+
+fn ui_margin(ui: &mut Ui, rates: &RateCard, job: &Job, quote: &Quote) {
+    let margin = GrossMargin::new(job, rates, quote);
+    let cost = job_cost(job, rates);
+    //with nothing to take off the price every job would show a 100% margin
+    let no_costs = cost.items.is_empty();
+    egui::Grid::new("margin").show(ui, |ui| {
+        ui.label("client price");
+        ui.label(margin.price.to_string());
+        ui.end_row();
+        ui.label("cost");
+        ui.label(margin.cost.to_string());
+        ui.end_row();
+        ui.label("gross margin");
+        let color = if margin.amount() < Money::ZERO { ui.visuals().error_fg_color } else { ui.visuals().text_color() };
+        ui.colored_label(color, margin.amount().to_string());
+        ui.end_row();
+        ui.label("margin");
+        match margin.percent() {
+            Some(percent) if !no_costs => ui.label(format!("{:.1}%", percent)),
+            _ => ui.label("-"),
+        };
+        ui.end_row();
+    });
+    if no_costs {
+        ui.colored_label(ui.visuals().warn_fg_color, "no pay rates or costs set in the rates file");
+    } else {
+        ui.collapsing("costs", |ui| ui_breakdown(ui, &cost));
+    }
+}
+
+fn ui_breakdown(ui: &mut Ui, quote: &Quote) {
+    egui::Grid::new("breakdown").striped(true).show(ui, |ui| {
+        ui.strong("item");
+        ui.strong("qty");
+        ui.strong("unit");
+        ui.strong("subtotal");
+        ui.end_row();
+        for item in &quote.items {
+            ui.label(&item.label);
+            ui.label(item.quantity.to_string());
+            ui.label(item.unit_price.to_string());
+            ui.label(item.subtotal.to_string());
+            ui.end_row();
+        }
+    });
+}
+
+//`in_use` is every photographer a shoot or saved quote names
+fn ui_rates(ui: &mut Ui, rates: &mut RateCard, library: Option<&QuoteLibrary>, in_use: &[String]) {
+    //quotes naming someone the rates file no longer has price them at nothing
+    for (name, quotes) in library.map(|library| library.off_roster(rates)).unwrap_or_default() {
+        let quotes = if quotes == 1 { "1 saved quote names".to_string() } else { format!("{} saved quotes name", quotes) };
+        ui.colored_label(ui.visuals().warn_fg_color, format!("{} {}, who is not on the roster", quotes, name));
+    }
+    egui::Grid::new("rates").show(ui, |ui| {
+        //nobody is removed, retiring keeps the rates for quotes that already name them
+        for (index, entry) in rates.photographers.0.iter_mut().enumerate() {
+            //a name in use is fixed, renaming would leave those shoots pricing at nothing,
+            //so a rename is retiring them and adding someone new. the one being typed in stays editable
+            let id = ui.id().with(("photographer name", index));
+            let named = in_use.iter().any(|name| name.trim().eq_ignore_ascii_case(entry.name.trim()));
+            if named && !ui.memory(|memory| memory.has_focus(id)) {
+                ui.heading(&entry.name).on_hover_text("quotes name them, retire them and add someone new to rename");
+            } else {
+                ui.add(TextEdit::singleline(&mut entry.name).id(id).desired_width(100.0).font(TextStyle::Heading));
+            }
             ui.horizontal(|ui| {
-                ui.add(DragValue::new(&mut self.travel_hours));
-                if self.travel_hours == 1 {
-                    ui.label("hour");
+                if entry.active {
+                    if ui.button("retire").clicked() {
+                        entry.active = false;
+                    }
                 } else {
-                    ui.label("hours");
+                    ui.weak("retired");
+                    if ui.button("reinstate").clicked() {
+                        entry.active = true;
+                    }
                 }
-            
-                if self.travel_hours > 2{
-                    ui.add(DragValue::new(&mut self.travel_days).clamp_range(2..=MAX_RANGE));
-                    if self.travel_days == 1 {
-                        ui.label("day");
-                    } else {
-                        ui.label("days");
+            });
+            ui.end_row();
+            rate_row(ui, "hourly", &mut entry.hourly);
+            rate_row(ui, "first half day", &mut entry.first_half_day);
+            rate_row(ui, "second half day", &mut entry.second_half_day);
+            rate_row(ui, "pay hourly", &mut entry.pay_hourly);
+            rate_row(ui, "pay per half day", &mut entry.pay_half_day);
+            ui.label("skills");
+            ui.horizontal(|ui| {
+                let mut remove = None;
+                for (index, skill) in entry.skills.iter_mut().enumerate() {
+                    ui.add(TextEdit::singleline(skill).desired_width(60.0));
+                    if ui.small_button("x").clicked() {
+                        remove = Some(index);
                     }
-                
-                    ui.add(DragValue::new(&mut self.travel_people));
-                    if self.travel_people == 1 {
-                        ui.label("person");
-                    } else {
-                        ui.label("people");
+                }
+                if let Some(index) = remove {
+                    entry.skills.remove(index);
+                }
+                if ui.small_button("+").clicked() {
+                    entry.skills.push(String::new());
+                }
+            });
+            ui.end_row();
+        }
+        if ui.button("add photographer").clicked() {
+            rates.photographers.add();
+        }
+        ui.end_row();
+
+        ui.strong("Extras");
+        ui.end_row();
+        rate_row(ui, "assistant hourly", &mut rates.assistant);
+        rate_row(ui, "higher assistant hourly", &mut rates.higher_assistant);
+        rate_row(ui, "image prep", &mut rates.image_prep);
+        rate_row(ui, "higher image prep", &mut rates.higher_image_prep);
+        rate_row(ui, "drone", &mut rates.drone);
+        rate_row(ui, "expense unit", &mut rates.expenses);
+
+        ui.strong("Headshots");
+        ui.end_row();
+        let mut remove = None;
+        for (index, package) in rates.headshot_packages.iter_mut().enumerate() {
+            ui.add(TextEdit::singleline(&mut package.name).desired_width(100.0).font(TextStyle::Heading));
+            if ui.button("remove").clicked() {
+                remove = Some(index);
+            }
+            ui.end_row();
+            ui.label("priced per session");
+            let mut per_session = package.session.is_some();
+            if ui.checkbox(&mut per_session, "").changed() {
+                package.session = per_session.then_some(SessionPricing {
+                    fee: Money::ZERO,
+                    included_heads: 1,
+                    per_extra_head: Money::ZERO,
+                    extra_day: Money::ZERO,
+                });
+            }
+            ui.end_row();
+            match &mut package.session {
+                Some(session) => {
+                    rate_row(ui, "session fee", &mut session.fee);
+                    ui.label("people included");
+                    ui.add(DragValue::new(&mut session.included_heads).clamp_range(0..=MAX_RANGE));
+                    ui.end_row();
+                    rate_row(ui, "each additional person", &mut session.per_extra_head);
+                    rate_row(ui, "each extra day", &mut session.extra_day);
+                }
+                None => rate_row(ui, "hourly", &mut package.hourly),
+            }
+            rate_row(ui, "photographer pay hourly", &mut package.pay_hourly);
+            ui.label("people per hour");
+            ui.add(DragValue::new(&mut package.heads_per_hour).clamp_range(1..=MAX_RANGE));
+            ui.end_row();
+            ui.label("set-up hours");
+            ui.add(DragValue::new(&mut package.setup_hours).speed(0.25).clamp_range(0.0..=24.0));
+            ui.end_row();
+            ui.label("assistants");
+            ui.add(DragValue::new(&mut package.assistants).speed(0.1).clamp_range(0.0..=MAX_RANGE as f32));
+            ui.end_row();
+            ui.label("retouching");
+            egui::ComboBox::from_id_source(("package retouching", index))
+                .selected_text(package.retouching.to_string())
+                .show_ui(ui, |ui| {
+                    for level in RetouchLevel::ALL {
+                        let text = level.to_string();
+                        ui.selectable_value(&mut package.retouching, level, text);
+                    }
+                });
+            ui.end_row();
+            ui.label("");
+            ui.checkbox(&mut package.requires_deposit, "requires a deposit");
+            ui.end_row();
+            ui.label("");
+            ui.checkbox(&mut package.on_site_editing, "on site editing offered");
+            ui.end_row();
+            ui.label("features");
+            ui.add(TextEdit::multiline(&mut package.features).desired_rows(3));
+            ui.end_row();
+        }
+        if let Some(index) = remove {
+            rates.headshot_packages.remove(index);
+        }
+        if ui.button("add package").clicked() {
+            rates.headshot_packages.push(HeadshotPackage::new("New package"));
+        }
+        ui.end_row();
+        rate_row(ui, "on site editing per day", &mut rates.on_site_editing);
+        rate_row(ui, "extra retouched photo", &mut rates.extra_retouch);
+
+        ui.strong("Retouching per person");
+        ui.end_row();
+        rate_row(ui, "student", &mut rates.retouch.student);
+        rate_row(ui, "discount", &mut rates.retouch.discount);
+        rate_row(ui, "corporate", &mut rates.retouch.corporate);
+        rate_row(ui, "full price", &mut rates.retouch.full);
+
+        ui.strong("Conference");
+        ui.end_row();
+        rate_row(ui, "hourly", &mut rates.conference_hourly);
+
+        ui.strong("Travel");
+        ui.end_row();
+        ui.label("short trip");
+        ui.add(DragValue::new(&mut rates.travel.short_trip_hours).speed(0.25).clamp_range(0.0..=24.0).suffix(" h"));
+        ui.end_row();
+        let short = rates.travel.short_trip_hours;
+        rate_row(ui, &format!("hourly up to {} hours", short), &mut rates.travel_short_hourly);
+        rate_row(ui, &format!("hourly over {} hours", short), &mut rates.travel_long_hourly);
+        rate_row(ui, "per diem (meals)", &mut rates.travel_per_diem);
+        rate_row(ui, "lodging per night", &mut rates.travel.lodging);
+        rate_row(ui, "per mile", &mut rates.travel.mileage);
+
+        ui.strong("Costs");
+        ui.end_row();
+        rate_row(ui, "assistant pay hourly", &mut rates.costs.assistant);
+        rate_row(ui, "higher assistant pay hourly", &mut rates.costs.higher_assistant);
+        rate_row(ui, "retoucher per image", &mut rates.costs.retouch_per_image);
+        rate_row(ui, "drone operator", &mut rates.costs.drone);
+        rate_row(ui, "conference photographer pay hourly", &mut rates.costs.conference_hourly);
+
+        let discounts = &mut rates.discounts;
+        ui.strong("Discounts");
+        ui.end_row();
+        percent_row(ui, "nonprofit", &mut discounts.nonprofit);
+        percent_row(ui, "student", &mut discounts.student);
+        let mut remove = None;
+        for (index, tier) in discounts.multi_day.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label("package from");
+                ui.add(DragValue::new(&mut tier.min_days).clamp_range(1..=MAX_RANGE));
+                ui.label("days");
+            });
+            ui.horizontal(|ui| {
+                ui.add(DragValue::new(&mut tier.percent).suffix("%").clamp_range(0.0..=100.0));
+                if ui.button("remove").clicked() {
+                    remove = Some(index);
+                }
+            });
+            ui.end_row();
+        }
+        if let Some(index) = remove {
+            discounts.multi_day.remove(index);
+        }
+        if ui.button("add package").clicked() {
+            discounts.multi_day.push(MultiDayDiscount { min_days: 2, percent: 0.0 });
+        }
+        ui.end_row();
+        for promo in &discounts.promo_codes {
+            ui.label(format!("code {}", promo.code));
+            ui.label(format!("{} ({})", promo.discount.name, promo.discount.amount));
+            ui.end_row();
+        }
+        ui.weak("promo codes are edited in the rates file");
+        ui.end_row();
+
+        ui.strong("Payments");
+        ui.end_row();
+        percent_row(ui, "deposit", &mut rates.payments.deposit_percent);
+        ui.label("delivery after the shoot");
+        ui.add(DragValue::new(&mut rates.payments.delivery_days).suffix(" days").clamp_range(0..=365));
+        ui.end_row();
+
+        ui.strong("Tax");
+        ui.end_row();
+        let mut remove = None;
+        for (index, jurisdiction) in rates.tax.jurisdictions.iter_mut().enumerate() {
+            ui.push_id(("jurisdiction", index), |ui| {
+                ui.add(TextEdit::singleline(&mut jurisdiction.name).desired_width(100.0));
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut jurisdiction.percent).suffix("%").speed(0.05).clamp_range(0.0..=100.0));
+                    kinds_combo(ui, "taxable", "nothing taxable", &mut jurisdiction.taxable);
+                    if ui.button("remove").clicked() {
+                        remove = Some(index);
                     }
+                });
+            });
+            ui.end_row();
+        }
+        if let Some(index) = remove {
+            rates.tax.jurisdictions.remove(index);
+        }
+        if ui.button("add jurisdiction").clicked() {
+            rates.tax.add();
+        }
+        ui.end_row();
+    });
+}
+
+fn percent_row(ui: &mut Ui, label: &str, percent: &mut f32) {
+    ui.label(label);
+    ui.add(DragValue::new(percent).suffix("%").clamp_range(0.0..=100.0));
+    ui.end_row();
+}
+
+fn ui_travel(ui: &mut Ui, rates: &RateCard, travel: &mut Travel) {
+    ui.horizontal(|ui| {
+        ui.label("travel for");
+        ui.add(DragValue::new(&mut travel.people).clamp_range(1..=MAX_RANGE));
+        ui.label(if travel.people == 1 { "person" } else { "people" });
+        ui.add(DragValue::new(&mut travel.nights).clamp_range(0..=MAX_RANGE));
+        ui.label(if travel.nights == 1 { "night" } else { "nights" });
+        ui.add(DragValue::new(&mut travel.meal_days).clamp_range(0..=MAX_RANGE));
+        ui.label(if travel.meal_days == 1 { "meal day" } else { "meal days" });
+    });
+
+    let mut remove = None;
+    for (index, leg) in travel.legs.iter_mut().enumerate() {
+        ui.push_id(("leg", index), |ui| {
+            ui.horizontal(|ui| {
+                ui.add(TextEdit::singleline(&mut leg.name).hint_text("leg").desired_width(100.0));
+                ui.add(DragValue::new(&mut leg.hours).speed(0.25).clamp_range(0.0..=f32::MAX).suffix(" h"));
+                ui.add(DragValue::new(&mut leg.miles).clamp_range(0.0..=f32::MAX).suffix(" mi"));
+                if ui.button("remove").clicked() {
+                    remove = Some(index);
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("flights per person");
+                money_drag(ui, &mut leg.flights, 1.0);
+                ui.label("tolls");
+                money_drag(ui, &mut leg.tolls, 1.0);
+                ui.label("parking");
+                money_drag(ui, &mut leg.parking, 1.0);
+            });
+        });
+    }
+    if let Some(index) = remove {
+        travel.legs.remove(index);
+    }
+    if ui.button("add travel leg").clicked() {
+        travel.legs.push(Leg::default());
+    }
+
+    let per_person = travel.per_person(rates).total();
+    let shared = travel.shared(rates).total();
+    if per_person != Money::ZERO || shared != Money::ZERO {
+        ui.weak(format!("{} per person, {} shared", per_person, shared));
+    }
+}
+
+fn ui_discounts(ui: &mut Ui, rates: &RateCard, job: &mut Job) {
+    egui::ComboBox::from_label("client rate")
+        .selected_text(job.client_rate.to_string())
+        .show_ui(ui, |ui| {
+            for rate in ClientRate::ALL {
+                ui.selectable_value(&mut job.client_rate, rate, rate.to_string());
+            }
+        });
+
+    ui.horizontal(|ui| {
+        ui.label("promo code");
+        ui.add(TextEdit::singleline(&mut job.promo_code).desired_width(120.0));
+        if !job.promo_code.trim().is_empty() {
+            match rates.discounts.promo(&job.promo_code) {
+                Some(promo) => ui.weak(format!("{} ({})", promo.discount.name, promo.discount.amount)),
+                None => ui.colored_label(ui.visuals().error_fg_color, "unknown code"),
+            };
+        }
+    });
+
+    let mut remove = None;
+    for (index, discount) in job.discounts.iter_mut().enumerate() {
+        ui.push_id(("discount", index), |ui| {
+            ui.horizontal(|ui| {
+                ui.add(TextEdit::singleline(&mut discount.name).desired_width(120.0));
+                let percent = matches!(discount.amount, DiscountAmount::Percent(_));
+                if ui.selectable_label(percent, "%").clicked() && !percent {
+                    discount.amount = DiscountAmount::Percent(0.0);
+                }
+                if ui.selectable_label(!percent, "$").clicked() && percent {
+                    discount.amount = DiscountAmount::Fixed(Money::ZERO);
                 }
+                match &mut discount.amount {
+                    DiscountAmount::Percent(percent) => {
+                        ui.add(DragValue::new(percent).suffix("%").clamp_range(0.0..=100.0))
+                    }
+                    DiscountAmount::Fixed(amount) => money_drag(ui, amount, 1.0),
+                };
+
+                kinds_combo(ui, "applies to", "whole quote", &mut discount.items);
 
+                if ui.button("remove").clicked() {
+                    remove = Some(index);
+                }
             });
+        });
+    }
+    if let Some(index) = remove {
+        job.discounts.remove(index);
+    }
+    if ui.button("add discount").clicked() {
+        job.discounts.push(Discount::percent("Discount", 0.0));
+    }
+}
+
+fn ui_payments(ui: &mut Ui, rates: &RateCard, job: &mut Job, details: &mut QuoteDetails, quote: &Quote) {
+    ui.horizontal(|ui| {
+        ui.label("shoot date");
+        date_edit(ui, "shoot date", &mut details.shoot_date);
+    });
+
+    let custom = matches!(job.payment_plan, PaymentPlan::Custom(_));
+    ui.horizontal(|ui| {
+        ui.label("payments");
+        if ui.selectable_label(!custom, "standard").clicked() {
+            job.payment_plan = PaymentPlan::Standard;
+        }
+        //custom plans start from the standard one
+        if ui.selectable_label(custom, "custom").clicked() && !custom {
+            job.payment_plan = PaymentPlan::Custom(rates.payments.standard(requires_deposit(job, rates)));
+        }
+    });
 
-            match &mut self.shoot_type {
-                ShootType::Hourly {
-                    hours,
-                    image_prep,
-                    assistant_hours,
-                    photographer,
-                    use_higher_assistant_price,
-                    use_higher_prep_price,
-                } => {
-                    ui_hourly(ui, hours, image_prep, assistant_hours, photographer, use_higher_prep_price, use_higher_assistant_price);
+    if let PaymentPlan::Custom(milestones) = &mut job.payment_plan {
+        let mut remove = None;
+        for (index, milestone) in milestones.iter_mut().enumerate() {
+            ui.push_id(("milestone", index), |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut milestone.name).desired_width(120.0));
+                    ui.add(DragValue::new(&mut milestone.percent).suffix("%").clamp_range(0.0..=100.0));
+                    let booking = milestone.due == Due::Booking;
+                    if ui.selectable_label(booking, "on booking").clicked() {
+                        milestone.due = Due::Booking;
+                    }
+                    if ui.selectable_label(!booking, "from shoot").clicked() && booking {
+                        milestone.due = Due::ShootDay(0);
+                    }
+                    if let Due::ShootDay(days) = &mut milestone.due {
+                        ui.add(DragValue::new(days).suffix(" days"));
+                    }
+                    if ui.button("remove").clicked() {
+                        remove = Some(index);
+                    }
+                });
+            });
+        }
+        if let Some(index) = remove {
+            milestones.remove(index);
+        }
+        if ui.button("add payment").clicked() {
+            milestones.push(Milestone { name: "Payment".to_string(), percent: 0.0, due: Due::ShootDay(0) });
+        }
+    }
+
+    match job.schedule(rates, quote) {
+        Ok(payments) => {
+            egui::Grid::new("payments").striped(true).show(ui, |ui| {
+                for payment in &payments {
+                    ui.label(&payment.name);
+                    ui.label(details.due_text(payment.due));
+                    ui.label(payment.amount.to_string());
+                    ui.end_row();
                 }
+            });
+        }
+        Err(err) => {
+            ui.colored_label(ui.visuals().error_fg_color, err.to_string());
+        }
+    }
+}
 
-                ShootType::HalfDayBased {
-                    halves,
-                    image_prep,
-                    assistant_hours,
-                    photographer,
-                    use_higher_assistant_price,
-                    use_higher_prep_price,
-                } => {
-                    ui_half_day_based(ui, halves, image_prep, assistant_hours, photographer, use_higher_prep_price, use_higher_assistant_price);
+fn ui_tax(ui: &mut Ui, rates: &RateCard, job: &mut Job) {
+    let jurisdictions = &rates.tax.jurisdictions;
+    if jurisdictions.is_empty() && job.jurisdiction.is_empty() {
+        return;
+    }
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_label("tax")
+            .selected_text(if job.jurisdiction.is_empty() { "no tax" } else { &job.jurisdiction })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut job.jurisdiction, String::new(), "no tax");
+                for jurisdiction in jurisdictions {
+                    let text = format!("{} ({}%)", jurisdiction.name, jurisdiction.percent);
+                    ui.selectable_value(&mut job.jurisdiction, jurisdiction.name.clone(), text);
                 }
+            });
+        if !job.jurisdiction.is_empty() && rates.tax.jurisdiction(&job.jurisdiction).is_none() {
+            ui.colored_label(ui.visuals().error_fg_color, "not in the rates file");
+        }
+    });
+}
 
-                ShootType::Headshot {
-                    heads,
-                    headshot_type,
-                    editing,
-                    retouch_level,
-                    extra_retouched_photos,
-                    days,
-                } => {
-                    ui_headshot(ui, heads, headshot_type, editing, retouch_level, extra_retouched_photos, days);
+//picks a set of item kinds, shows `none` when nothing is picked
+fn kinds_combo(ui: &mut Ui, id_source: &str, none: &str, kinds: &mut Vec<ItemKind>) {
+    let text = if kinds.is_empty() {
+        none.to_string()
+    } else {
+        kinds.iter().map(ItemKind::to_string).collect::<Vec<_>>().join(", ")
+    };
+    egui::ComboBox::from_id_source(id_source).selected_text(text).show_ui(ui, |ui| {
+        if ui.selectable_label(kinds.is_empty(), none).clicked() {
+            kinds.clear();
+        }
+        for kind in ItemKind::CHARGES {
+            let mut picked = kinds.contains(&kind);
+            if ui.checkbox(&mut picked, kind.to_string()).changed() {
+                if picked {
+                    kinds.push(kind);
+                } else {
+                    kinds.retain(|item| *item != kind);
                 }
-                ShootType::Conference { hours , extra_cost} => {
-                    ui_conference(ui, hours, extra_cost)
+            }
+        }
+    });
+}
+
+//active photographers from the roster, a retired or removed one stays selected until changed
+fn photographer_combo(ui: &mut Ui, rates: &RateCard, photographer: &mut Photographer) {
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_label("Photographer")
+            .selected_text(photographer.to_string())
+            .show_ui(ui, |ui| {
+                for entry in rates.photographers.active() {
+                    let text = if entry.skills.is_empty() {
+                        entry.name.clone()
+                    } else {
+                        format!("{} ({})", entry.name, entry.skills.join(", "))
+                    };
+                    ui.selectable_value(photographer, Photographer::new(entry.name.as_str()), text);
                 }
+            });
+        match photographer.rates(rates) {
+            Some(entry) if !entry.active => {
+                ui.colored_label(ui.visuals().warn_fg_color, "retired");
             }
-            if ui
-                .add(
-                    Label::new(format!("${}", self.calc_price().to_string())).sense(Sense::click()),
-                )
-                .on_hover_text("click to copy")
-                .clicked()
-            {
-                ui.output_mut(|o| o.copied_text = self.calc_price().to_string());
-            };
+            Some(_) => {}
+            None => {
+                ui.colored_label(ui.visuals().error_fg_color, "not on the roster");
+            }
+        }
+    });
+}
+
+//everyone else on the shoot, each priced on their own line
+fn ui_crew(ui: &mut Ui, rates: &RateCard, crew: &mut Vec<CrewMember>, half_day_shoot: bool) {
+    let mut remove = None;
+    for (index, member) in crew.iter_mut().enumerate() {
+        ui.push_id(("crew", index), |ui| {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("role")
+                    .selected_text(member.role.to_string())
+                    .show_ui(ui, |ui| {
+                        for role in CrewRole::ALL {
+                            ui.selectable_value(&mut member.role, role, role.to_string());
+                        }
+                    });
+                egui::ComboBox::from_id_source("rate")
+                    .selected_text(member.rate.to_string())
+                    .show_ui(ui, |ui| {
+                        for entry in rates.photographers.active() {
+                            let rate = CrewRate::Roster(Photographer::new(entry.name.as_str()));
+                            ui.selectable_value(&mut member.rate, rate, &entry.name);
+                        }
+                        ui.selectable_value(&mut member.rate, CrewRate::Assistant, format!("Assistant ({})", rates.assistant));
+                        ui.selectable_value(
+                            &mut member.rate,
+                            CrewRate::HigherAssistant,
+                            format!("Higher rate assistant ({})", rates.higher_assistant),
+                        );
+                    });
+                if member.by_half_day(half_day_shoot) {
+                    ui.add(DragValue::new(&mut member.halves).clamp_range(0..=MAX_RANGE));
+                    ui.label("half days");
+                } else {
+                    ui.add(DragValue::new(&mut member.hours).clamp_range(0.0..=f32::MAX));
+                    ui.label("hours");
+                }
+                if ui.button("remove").clicked() {
+                    remove = Some(index);
+                }
+                if let CrewRate::Roster(photographer) = &member.rate {
+                    if photographer.rates(rates).is_none() {
+                        ui.colored_label(ui.visuals().error_fg_color, "not on the roster");
+                    }
+                }
+            });
         });
     }
+    if let Some(index) = remove {
+        crew.remove(index);
+    }
+    if ui.button("add crew member").clicked() {
+        crew.push(CrewMember { rate: CrewRate::Roster(Photographer::first(rates)), ..CrewMember::default() });
+    }
+}
+
+fn rate_row(ui: &mut Ui, label: &str, rate: &mut Money) {
+    ui.label(label);
+    money_drag(ui, rate, 1.0);
+    ui.end_row();
 }
 
+#[allow(clippy::too_many_arguments)]
 fn ui_hourly(
     ui: &mut Ui,
+    rates: &RateCard,
     hours: &mut f32,
     image_prep: &mut bool,
     assistant_hours: &mut f32,
@@ -158,15 +1088,9 @@ fn ui_hourly(
     use_higher_prep_price: &mut bool, 
     use_higher_assistant_price: &mut bool,
 ) {
-    egui::ComboBox::from_label("Photographer")
-        .selected_text(photographer.to_string())
-        .show_ui(ui, |ui| {
-            ui.selectable_value(photographer, Photographer::Ken, "Ken");
-            ui.selectable_value(photographer, Photographer::Colin, "Colin");
-            ui.selectable_value(photographer, Photographer::Team, "Team");
-        });
+    photographer_combo(ui, rates, photographer);
     ui.horizontal(|ui| {
-        ui.add(DragValue::new(hours));
+        ui.add(DragValue::new(hours).clamp_range(0.0..=f32::MAX));
         if *hours == 1.0 {
             ui.label("hour");
         } else {
@@ -175,7 +1099,7 @@ fn ui_hourly(
     });
 
     ui.horizontal(|ui| {
-        ui.add(DragValue::new(assistant_hours));
+        ui.add(DragValue::new(assistant_hours).clamp_range(0.0..=f32::MAX));
         if *assistant_hours == 1.0 {
             ui.label("assistant hour");
         } else {
@@ -188,8 +1112,10 @@ fn ui_hourly(
     ui.checkbox(use_higher_prep_price, "use higher image prep price");
 }
 
+#[allow(clippy::too_many_arguments)]
 fn ui_half_day_based(
     ui: &mut Ui,
+    rates: &RateCard,
     halves: &mut u32,
     image_prep: &mut bool,
     assistant_hours: &mut f32,
@@ -197,20 +1123,14 @@ fn ui_half_day_based(
     use_higher_prep_price: &mut bool, 
     use_higher_assistant_price: &mut bool,
 ) {
-    egui::ComboBox::from_label("Photographer")
-        .selected_text(photographer.to_string())
-        .show_ui(ui, |ui| {
-            ui.selectable_value(photographer, Photographer::Ken, "Ken");
-            ui.selectable_value(photographer, Photographer::Colin, "Colin");
-            ui.selectable_value(photographer, Photographer::Team, "Team");
-        });
+    photographer_combo(ui, rates, photographer);
     ui.horizontal(|ui| {
         ui.add(DragValue::new(halves));
         ui.label("number of half days");
     });
 
     ui.horizontal(|ui| {
-        ui.add(DragValue::new(assistant_hours));
+        ui.add(DragValue::new(assistant_hours).clamp_range(0.0..=f32::MAX));
         if *assistant_hours == 1.0 {
             ui.label("assistant hour");
         } else {
@@ -223,8 +1143,10 @@ fn ui_half_day_based(
     ui.checkbox(use_higher_prep_price, "use higher image prep price");
 }
 
+#[allow(clippy::too_many_arguments)]
 fn ui_headshot(
     ui: &mut Ui,
+    rates: &RateCard,
     heads: &mut u32,
     headshot_type: &mut HeadshotType,
     editing: &mut bool,
@@ -232,21 +1154,45 @@ fn ui_headshot(
     extra_retouched_photos: &mut u32,
     days: &mut u32,
 ) {
-    egui::ComboBox::from_label("Headshot type")
-        .selected_text(headshot_type.to_string())
-        .show_ui(ui, |ui| {
-            ui.selectable_value(headshot_type, HeadshotType::Large, "Large");
-            ui.selectable_value(headshot_type, HeadshotType::Team, "Team");
-            ui.selectable_value(headshot_type, HeadshotType::Small, "Small");
-        });
-    
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_label("Headshot type")
+            .selected_text(headshot_type.to_string())
+            .show_ui(ui, |ui| {
+                for package in &rates.headshot_packages {
+                    let picked = HeadshotType::new(package.name.as_str());
+                    let selected = *headshot_type == picked;
+                    //a new package starts from the retouching it includes
+                    if ui.selectable_label(selected, &package.name).clicked() && !selected {
+                        *headshot_type = picked;
+                        *retouch_level = package.retouching.clone();
+                    }
+                }
+            });
+        if headshot_type.package(rates).is_none() {
+            ui.colored_label(ui.visuals().error_fg_color, "not in the rates file");
+        }
+    });
+    //only the inputs the package is priced from
+    let Some(package) = headshot_type.package(rates) else {
+        return;
+    };
+
+    //session packages include their retouching, a better level costs the difference
+    let retouch_text = |level: &RetouchLevel| {
+        let price = level.get_price_per(rates);
+        match &package.session {
+            Some(_) if *level == package.retouching => format!("{} (included)", level),
+            Some(_) => format!("{} +{}", level, (price - package.retouching.get_price_per(rates)).max(Money::ZERO)),
+            None => format!("{} {}", level, price),
+        }
+    };
     egui::ComboBox::from_label("Retouching type")
-        .selected_text(retouch_level.to_string())
+        .selected_text(retouch_text(retouch_level))
         .show_ui(ui, |ui| {
-            ui.selectable_value(retouch_level, RetouchLevel::Student, RetouchLevel::Student.to_string());
-            ui.selectable_value(retouch_level, RetouchLevel::Discount, RetouchLevel::Discount.to_string());
-            ui.selectable_value(retouch_level, RetouchLevel::Corporate, RetouchLevel::Corporate.to_string());
-            ui.selectable_value(retouch_level, RetouchLevel::Full, RetouchLevel::Full.to_string());
+            for level in RetouchLevel::ALL {
+                let text = retouch_text(&level);
+                ui.selectable_value(retouch_level, level, text);
+            }
         });
 
     ui.horizontal(|ui| {
@@ -276,68 +1222,29 @@ fn ui_headshot(
         }
     });
 
-    ui.checkbox(editing, "on site editing");
+    if package.on_site_editing {
+        ui.checkbox(editing, "on site editing");
+    }
 
     //extra text
     ui.separator();
-    match headshot_type {
-        HeadshotType::Large => {
-            let text = format!(
-"Features:
-nice retouching included
-on-site photo choice on iPad
-12/people per hour
-for up to {} photo hours (plus one extra hour on site for set-up & teardown)",
-                calc_hours(*heads) - 1.0
-            );
-            if ui
-                .add(Label::new(text.clone()).sense(Sense::click()))
-                .on_hover_text("click to copy")
-                .clicked()
-            {
-                ui.output_mut(|o| o.copied_text = text.to_string());
-            };
-        }
-        HeadshotType::Team => {
-            let text = 
-"Features:
-business-level retouching included (blemishes, flyaway hair
-on-site photo choice on iPad
-Online sign up & direct email delivery to subjects
-12/people per hour
-for up to 1 photo hour (plus one extra hour on site for set-up & teardown)";
-            if ui
-                .add(Label::new(text).sense(Sense::click()))
-                .on_hover_text("click to copy")
-                .clicked()
-            {
-                ui.output_mut(|o| o.copied_text = text.to_string());
-            };
-        }
-        HeadshotType::Small => {
-            let text = 
-"Features:
-simple lighting as needed
-minor retouching included (blemishes)
-fully trained HuthPhoto Team photographer";
-            if ui
-                .add(Label::new(text).sense(Sense::click()))
-                .on_hover_text("click to copy")
-                .clicked()
-            {
-                ui.output_mut(|o| o.copied_text = text.to_string());
-            };
-        }
-    }
+    let text = package.features_text(*heads);
+    if ui
+        .add(Label::new(text.clone()).sense(Sense::click()))
+        .on_hover_text("click to copy")
+        .clicked()
+    {
+        ui.output_mut(|o| o.copied_text = text);
+    };
 }
 
 fn ui_conference(
     ui: &mut Ui,
     hours: &mut f32,
-    extra_cost: &mut f32,
+    extra_cost: &mut Money,
 ) {
     ui.horizontal(|ui| {
-        ui.add(DragValue::new(hours).speed(0.1));
+        ui.add(DragValue::new(hours).speed(0.1).clamp_range(0.0..=f32::MAX));
         if *hours == 1.0 {
             ui.label("hour");
         } else {
@@ -346,7 +1253,41 @@ fn ui_conference(
     });
     
     ui.horizontal(|ui| {
-        ui.add(DragValue::new(extra_cost).speed(25.0));
+        money_drag(ui, extra_cost, 25.0);
         ui.label("retouching / extra costs");
     });
 }
+
+//edits a Money in dollars, keeping the stored value rounded to the cent
+fn money_drag(ui: &mut Ui, value: &mut Money, speed: f64) -> Response {
+    let mut dollars = value.as_dollars_f64();
+    let response = ui.add(
+        DragValue::new(&mut dollars)
+            .speed(speed)
+            .prefix("$")
+            .max_decimals(2)
+            .clamp_range(0.0..=f64::MAX),
+    );
+    if response.changed() {
+        *value = Money::from_dollars_f64(dollars);
+    }
+    response
+}
+
+//typed as YYYY-MM-DD, only written back once it parses
+fn date_edit(ui: &mut Ui, id_source: &str, date: &mut NaiveDate) {
+    let id = ui.make_persistent_id(id_source);
+    let mut text = ui.data_mut(|d| d.get_temp::<String>(id)).unwrap_or_else(|| date.to_string());
+    let response = ui.add(TextEdit::singleline(&mut text).desired_width(100.0));
+    let parsed = NaiveDate::parse_from_str(&text, "%Y-%m-%d");
+    if let Ok(parsed) = parsed {
+        *date = parsed;
+    } else {
+        ui.colored_label(ui.visuals().error_fg_color, "YYYY-MM-DD");
+    }
+    if response.has_focus() {
+        ui.data_mut(|d| d.insert_temp(id, text));
+    } else {
+        ui.data_mut(|d| d.remove::<String>(id));
+    }
+}